@@ -4,11 +4,11 @@ use hashbrown::HashMap;
 use hashbrown::HashSet;
 use utils::grid::Direction;
 use utils::grid::Grid;
-use utils::solution::Solution;
+use utils::solution::PhasedSolution;
 use utils::solution::Solver;
 
 inventory::submit! {
-    Solver::new(2024, 15, solve_all)
+    Solver::phased::<Day15>(2024, 15)
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -226,12 +226,22 @@ fn solve_part_two(grid: &Grid<char>, directions: &[Direction]) -> Result<usize>
     Ok(calculate_gps_coords(&grid))
 }
 
-fn solve_all(input: &str) -> Result<Solution> {
-    let (grid, directions) = parse_input(input)?;
-    let part_one = solve_part_one(&mut grid.clone(), &directions)?.to_string();
-    let part_two = solve_part_two(&grid, &directions)?.to_string();
+struct Day15;
 
-    Ok(Solution { part_one, part_two })
+impl PhasedSolution for Day15 {
+    type Parsed = (Grid<char>, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part_one((grid, directions): &mut Self::Parsed) -> Result<String> {
+        Ok(solve_part_one(&mut grid.clone(), directions)?.to_string())
+    }
+
+    fn part_two((grid, directions): &mut Self::Parsed) -> Result<String> {
+        Ok(solve_part_two(grid, directions)?.to_string())
+    }
 }
 
 #[cfg(test)]
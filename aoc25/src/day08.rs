@@ -1,12 +1,11 @@
 use anyhow::anyhow;
 use itertools::Itertools;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-use utils::solution::Solution;
+use std::cell::OnceCell;
+use utils::solution::PhasedSolution;
 use utils::solution::Solver;
 
 inventory::submit! {
-    Solver::new(2025, 8, solve_all)
+    Solver::phased::<Day08>(2025, 8)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -92,34 +91,35 @@ impl DisjointSet {
     }
 }
 
-fn build_connection_order(points: &[Point3D]) -> BinaryHeap<Reverse<Pair>> {
-    (0..points.len())
+/// Every pair of points, closest first.
+fn build_connection_order(points: &[Point3D]) -> Vec<Pair> {
+    let mut pairs = (0..points.len())
         .array_combinations::<2>()
-        .map(|pair| {
-            Reverse(Pair {
-                index_a: pair[0],
-                index_b: pair[1],
-                dist: points[pair[0]].euclid_dist(&points[pair[1]]),
-            })
+        .map(|pair| Pair {
+            index_a: pair[0],
+            index_b: pair[1],
+            dist: points[pair[0]].euclid_dist(&points[pair[1]]),
         })
-        .collect::<BinaryHeap<_>>()
+        .collect_vec();
+    pairs.sort_unstable();
+    pairs
 }
 
 fn solve_part_one(
     points: &[Point3D],
-    pairs: &mut BinaryHeap<Reverse<Pair>>,
+    pairs: &[Pair],
     max_connections: usize,
 ) -> anyhow::Result<u64> {
     if points.len() < 2 {
         return Err(anyhow!("Not enough points to form pairs"));
     }
+    if pairs.len() < max_connections {
+        return Err(anyhow!("Ran out of pairs while connecting components"));
+    }
 
     let mut circuits = DisjointSet::new(points.len());
 
-    for _ in 0..max_connections {
-        let Reverse(pair) = pairs
-            .pop()
-            .ok_or_else(|| anyhow!("Ran out of pairs while connecting components"))?;
+    for pair in &pairs[..max_connections] {
         circuits.union(pair.index_a, pair.index_b);
     }
 
@@ -132,29 +132,22 @@ fn solve_part_one(
     Ok(counts.iter().rev().take(3).product())
 }
 
-fn solve_part_two(points: &[Point3D], mut pairs: BinaryHeap<Reverse<Pair>>) -> anyhow::Result<u64> {
+fn solve_part_two(points: &[Point3D], pairs: &[Pair]) -> anyhow::Result<u64> {
     if points.len() < 2 {
         return Err(anyhow!("Not enough points to form pairs"));
     }
 
     let mut circuits = DisjointSet::new(points.len());
 
-    let Reverse(mut pair) = pairs.pop().ok_or_else(|| anyhow!("No pairs available"))?;
-
-    loop {
+    for pair in pairs {
         circuits.union(pair.index_a, pair.index_b);
         if circuits.is_fully_connected() {
-            break;
+            let x_a = points[pair.index_a].x;
+            let x_b = points[pair.index_b].x;
+            return Ok(x_a * x_b);
         }
-        let Reverse(next_pair) = pairs
-            .pop()
-            .ok_or_else(|| anyhow!("Ran out of pairs before full connectivity"))?;
-        pair = next_pair;
     }
-
-    let x_a = points[pair.index_a].x;
-    let x_b = points[pair.index_b].x;
-    Ok(x_a * x_b)
+    Err(anyhow!("Ran out of pairs before full connectivity"))
 }
 
 fn parse_input(input: &str) -> anyhow::Result<Vec<Point3D>> {
@@ -179,14 +172,38 @@ fn parse_input(input: &str) -> anyhow::Result<Vec<Point3D>> {
         .collect()
 }
 
-fn solve_all(input: &str) -> anyhow::Result<Solution> {
-    let points = parse_input(input)?;
-    let mut pairs = build_connection_order(&points);
+struct Day08;
 
-    let part_one = solve_part_one(&points, &mut pairs, 1000)?.to_string();
-    let part_two = solve_part_two(&points, pairs)?.to_string();
+struct Junctions {
+    points: Vec<Point3D>,
+    /// The connection order, built by whichever part needs it first.
+    pairs: OnceCell<Vec<Pair>>,
+}
 
-    Ok(Solution { part_one, part_two })
+impl Junctions {
+    fn pairs(&self) -> &[Pair] {
+        self.pairs
+            .get_or_init(|| build_connection_order(&self.points))
+    }
+}
+
+impl PhasedSolution for Day08 {
+    type Parsed = Junctions;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Junctions {
+            points: parse_input(input)?,
+            pairs: OnceCell::new(),
+        })
+    }
+
+    fn part_one(junctions: &mut Self::Parsed) -> anyhow::Result<String> {
+        Ok(solve_part_one(&junctions.points, junctions.pairs(), 1000)?.to_string())
+    }
+
+    fn part_two(junctions: &mut Self::Parsed) -> anyhow::Result<String> {
+        Ok(solve_part_two(&junctions.points, junctions.pairs())?.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -201,8 +218,8 @@ mod tests {
     fn part_one() {
         let input = load_test_input!();
         let points = parse_input(&input).unwrap();
-        let mut pairs = build_connection_order(&points);
-        let solution = solve_part_one(&points, &mut pairs, 10).unwrap();
+        let pairs = build_connection_order(&points);
+        let solution = solve_part_one(&points, &pairs, 10).unwrap();
         assert_eq!(solution, 40);
     }
 
@@ -211,7 +228,7 @@ mod tests {
         let input = load_test_input!();
         let points = parse_input(&input).unwrap();
         let pairs = build_connection_order(&points);
-        let solution = solve_part_two(&points, pairs).unwrap();
+        let solution = solve_part_two(&points, &pairs).unwrap();
         assert_eq!(solution, 25272);
     }
 }
@@ -1,10 +1,11 @@
 use anyhow::anyhow;
 use rayon::prelude::*;
-use utils::solution::Solution;
+use std::cell::OnceCell;
+use utils::solution::PhasedSolution;
 use utils::solution::Solver;
 
 inventory::submit! {
-    Solver::new(2025, 9, solve_all)
+    Solver::phased::<Day09>(2025, 9)
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -94,13 +95,38 @@ fn parse_input(input: &str) -> anyhow::Result<Vec<(usize, usize)>> {
     Ok(points)
 }
 
-fn solve_all(input: &str) -> anyhow::Result<Solution> {
-    let points = parse_input(input)?;
-    let rectangles = calc_all_rectangles(&points);
-    let part_one = solve_part_one(&rectangles)?.to_string();
-    let part_two = solve_part_two(&points, &rectangles)?.to_string();
+struct Day09;
 
-    Ok(Solution { part_one, part_two })
+struct Tiles {
+    points: Vec<(usize, usize)>,
+    /// Every rectangle between two red tiles, built by whichever part needs it first.
+    rectangles: OnceCell<Vec<Rectangle>>,
+}
+
+impl Tiles {
+    fn rectangles(&self) -> &[Rectangle] {
+        self.rectangles
+            .get_or_init(|| calc_all_rectangles(&self.points))
+    }
+}
+
+impl PhasedSolution for Day09 {
+    type Parsed = Tiles;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Tiles {
+            points: parse_input(input)?,
+            rectangles: OnceCell::new(),
+        })
+    }
+
+    fn part_one(tiles: &mut Self::Parsed) -> anyhow::Result<String> {
+        Ok(solve_part_one(tiles.rectangles())?.to_string())
+    }
+
+    fn part_two(tiles: &mut Self::Parsed) -> anyhow::Result<String> {
+        Ok(solve_part_two(&tiles.points, tiles.rectangles())?.to_string())
+    }
 }
 
 #[cfg(test)]
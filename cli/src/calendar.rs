@@ -0,0 +1,102 @@
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+/// First year of Advent of Code.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Last year accepted by `--year`. A fixed bound rather than the current date,
+/// so that the same arguments parse the same way whenever they are given.
+pub const LAST_YEAR: u16 = 2099;
+
+/// Number of puzzle days in an event. Events up to 2024 ran for 25 days,
+/// later ones for 12.
+pub fn days_in_event(year: u16) -> u8 {
+    if year < 2025 { 25 } else { 12 }
+}
+
+/// Converts a civil date into days since the Unix epoch.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Howard Hinnant's `days_from_civil` algorithm.
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// When a puzzle unlocks: midnight EST (05:00 UTC) on its day in December.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_from_civil(i64::from(year), 12, u32::from(day));
+    UNIX_EPOCH + Duration::from_secs((days * 86_400 + 5 * 3_600) as u64)
+}
+
+pub fn is_unlocked(year: u16, day: u8) -> bool {
+    SystemTime::now() >= unlock_time(year, day)
+}
+
+/// Formats a countdown as `HH:MM:SS`, prefixed with the number of days if there are any.
+/// Seconds are rounded up, so the countdown only shows zero once it has run out.
+pub fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (days, hours) = (seconds / 86_400, seconds / 3_600 % 24);
+    let (minutes, seconds) = (seconds / 60 % 60, seconds % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::days_from_civil;
+    use super::days_in_event;
+    use super::format_countdown;
+    use super::unlock_time;
+    use std::time::Duration;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn convert_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 2, 29), 11_016);
+        assert_eq!(days_from_civil(2024, 12, 1), 20_058);
+    }
+
+    #[test]
+    fn unlock_times() {
+        // 2024-12-01T05:00:00Z
+        assert_eq!(
+            unlock_time(2024, 1),
+            UNIX_EPOCH + Duration::from_secs(1_733_029_200)
+        );
+        assert_eq!(
+            unlock_time(2024, 25)
+                .duration_since(unlock_time(2024, 1))
+                .unwrap(),
+            Duration::from_secs(24 * 86_400)
+        );
+    }
+
+    #[test]
+    fn event_length() {
+        assert_eq!(days_in_event(2015), 25);
+        assert_eq!(days_in_event(2024), 25);
+        assert_eq!(days_in_event(2025), 12);
+    }
+
+    #[test]
+    fn countdowns() {
+        assert_eq!(format_countdown(Duration::ZERO), "00:00:00");
+        assert_eq!(format_countdown(Duration::from_millis(1)), "00:00:01");
+        assert_eq!(format_countdown(Duration::from_secs(3_725)), "01:02:05");
+        assert_eq!(
+            format_countdown(Duration::from_secs(2 * 86_400 + 59)),
+            "2d 00:00:59"
+        );
+    }
+}
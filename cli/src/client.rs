@@ -0,0 +1,267 @@
+use anyhow::Context;
+use reqwest::StatusCode;
+use reqwest::blocking::RequestBuilder;
+use runner::config;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool and its author, as Advent of Code asks automated tools to.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "aoc-cli/",
+    env!("CARGO_PKG_VERSION"),
+    " by ",
+    env!("CARGO_PKG_AUTHORS")
+);
+
+/// Thin wrapper around the Advent of Code website, authenticated with the
+/// `AOC_SESSION` cookie. The base URL can be overridden with `AOC_BASE_URL`,
+/// e.g. to point the CLI at a local stand-in server.
+pub struct AocClient {
+    base_url: String,
+    session_token: String,
+    http: reqwest::blocking::Client,
+    throttle: Option<Throttle>,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session_token: &str, user_agent: &str) -> anyhow::Result<Self> {
+        let http = reqwest::blocking::Client::builder()
+            .user_agent(user_agent)
+            .build()?;
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session_token: session_token.to_string(),
+            http,
+            throttle: None,
+        })
+    }
+
+    /// Spaces requests according to `throttle`.
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = Some(throttle);
+        self
+    }
+
+    /// Builds a client from the session token, endpoint, User-Agent and throttle
+    /// configured for the workspace: `AOC_SESSION` and `AOC_BASE_URL` unless
+    /// `aoc.toml` says otherwise.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let workspace = config::workspace();
+        let session_token = workspace.session_token()?;
+        let base_url = workspace
+            .base_url()
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let user_agent = workspace
+            .config
+            .user_agent
+            .as_deref()
+            .unwrap_or(DEFAULT_USER_AGENT);
+        let throttle = Throttle {
+            path: workspace.throttle_path(),
+            interval: Duration::from_millis(workspace.config.request_interval_ms),
+        };
+        Ok(Self::new(&base_url, &session_token, user_agent)?.with_throttle(throttle))
+    }
+
+    /// Downloads the puzzle input for the given day.
+    pub fn get_input(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        self.send(self.http.get(&url), &url)
+    }
+
+    /// Downloads the puzzle page, which includes part two once part one is solved.
+    pub fn get_puzzle(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        self.send(self.http.get(&url), &url)
+    }
+
+    /// Posts `answer` for the given puzzle part and returns the raw HTML response.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> anyhow::Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let request = self
+            .http
+            .post(&url)
+            .form(&[("level", level.as_str()), ("answer", answer)]);
+        self.send(request, &url)
+    }
+
+    fn send(&self, request: RequestBuilder, url: &str) -> anyhow::Result<String> {
+        if let Some(throttle) = &self.throttle {
+            throttle.wait()?;
+        }
+        let response = request
+            .header("cookie", format!("session={}", self.session_token))
+            .send()
+            .with_context(|| format!("Failed to reach {url}"))?;
+        let status = response.status();
+        let body = response.text()?;
+        if !status.is_success() {
+            return Err(ResponseError::new(status, &body).into());
+        }
+        Ok(body)
+    }
+}
+
+/// Keeps requests at least `interval` apart. The time of the last request is
+/// stored in `path`, so separate invocations of the CLI are spaced out too.
+pub struct Throttle {
+    pub path: PathBuf,
+    pub interval: Duration,
+}
+
+impl Throttle {
+    /// Sleeps until the next request is allowed, then records it as sent.
+    fn wait(&self) -> anyhow::Result<()> {
+        if let Some(last) = self.last_request() {
+            let next = last + self.interval;
+            if let Ok(remaining) = next.duration_since(SystemTime::now()) {
+                println!(
+                    "Waiting {:.1}s before the next request...",
+                    remaining.as_secs_f64()
+                );
+                std::thread::sleep(remaining);
+            }
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        std::fs::write(&self.path, now.as_millis().to_string())
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let millis = std::fs::read_to_string(&self.path)
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+}
+
+/// An unsuccessful response from Advent of Code.
+#[derive(Debug)]
+pub struct ResponseError {
+    /// The puzzle has not unlocked yet.
+    pub locked: bool,
+    message: String,
+}
+
+impl ResponseError {
+    fn new(status: StatusCode, body: &str) -> Self {
+        Self {
+            locked: is_locked(status, body),
+            message: describe_failure(status, body),
+        }
+    }
+}
+
+impl std::fmt::Display for ResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ResponseError {}
+
+/// Whether `err` comes from a request for a puzzle that has not unlocked yet.
+pub fn is_locked_error(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<ResponseError>()
+        .is_some_and(|err| err.locked)
+}
+
+fn is_locked(status: StatusCode, body: &str) -> bool {
+    !body.contains("log in")
+        && (status == StatusCode::NOT_FOUND || body.contains("before it unlocks"))
+}
+
+/// Turns an unsuccessful response into a message explaining what went wrong.
+fn describe_failure(status: StatusCode, body: &str) -> String {
+    let reason = if body.contains("log in") {
+        "The session token is invalid or has expired. Update AOC_SESSION in `.env`.".to_string()
+    } else if is_locked(status, body) {
+        "The puzzle is not unlocked yet.".to_string()
+    } else if status == StatusCode::BAD_REQUEST {
+        "The request was rejected. Make sure your token is correct and up-to-date.".to_string()
+    } else if status.is_server_error() {
+        "The server had an internal error. Try again later.".to_string()
+    } else {
+        "Unexpected response from the server.".to_string()
+    };
+    format!("{reason} (HTTP {})", status.as_u16())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Throttle;
+    use super::describe_failure;
+    use super::is_locked;
+    use reqwest::StatusCode;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    #[test]
+    fn failure_messages() {
+        let expired = describe_failure(
+            StatusCode::BAD_REQUEST,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        assert!(expired.contains("expired"));
+
+        let locked = describe_failure(
+            StatusCode::NOT_FOUND,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        );
+        assert!(locked.contains("not unlocked"));
+
+        let bad_request = describe_failure(StatusCode::BAD_REQUEST, "");
+        assert!(bad_request.contains("rejected"));
+
+        let server_error = describe_failure(StatusCode::INTERNAL_SERVER_ERROR, "");
+        assert!(server_error.contains("internal error"));
+        assert!(server_error.ends_with("(HTTP 500)"));
+    }
+
+    #[test]
+    fn locked_responses() {
+        assert!(is_locked(StatusCode::NOT_FOUND, ""));
+        assert!(is_locked(
+            StatusCode::BAD_REQUEST,
+            "Please don't repeatedly request this endpoint before it unlocks!"
+        ));
+        assert!(!is_locked(
+            StatusCode::NOT_FOUND,
+            "Please log in to get your puzzle input."
+        ));
+        assert!(!is_locked(StatusCode::INTERNAL_SERVER_ERROR, ""));
+    }
+
+    #[test]
+    fn throttle_persists_between_instances() {
+        let dir = tempfile::tempdir().unwrap();
+        let throttle = || Throttle {
+            path: dir.path().join("state/last-request"),
+            interval: Duration::from_millis(200),
+        };
+
+        let started = Instant::now();
+        throttle().wait().unwrap();
+        assert!(started.elapsed() < Duration::from_millis(200));
+        throttle().wait().unwrap();
+        // The last request is stored with millisecond precision.
+        assert!(started.elapsed() >= Duration::from_millis(199));
+    }
+}
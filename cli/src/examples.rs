@@ -0,0 +1,137 @@
+use crate::html;
+use crate::html::Element;
+use crate::html::Node;
+use anyhow::Context;
+use runner::ANSWER_SUFFIX;
+use runner::config;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// A `<pre><code>` block found in a puzzle description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// Puzzle part (1 or 2) whose description contains the block.
+    pub part: u8,
+    pub text: String,
+}
+
+/// Example blocks and expected answers found in a saved puzzle page.
+#[derive(Debug, Default)]
+pub struct Examples {
+    pub blocks: Vec<Example>,
+    /// Last emphasized `<code><em>` value of each part, which is usually the
+    /// answer for the example.
+    pub answers: [Option<String>; 2],
+}
+
+impl Examples {
+    pub fn from_html(page: &str) -> Self {
+        let nodes = html::parse(page);
+        let articles = html::find_all(&nodes, &|e| e.name == "article" && e.has_class("day-desc"));
+
+        let mut examples = Examples::default();
+        for (index, article) in articles.iter().take(2).enumerate() {
+            let part = index as u8 + 1;
+            for pre in html::find_all(&article.children, &|e| e.name == "pre") {
+                examples.blocks.push(Example {
+                    part,
+                    text: normalize(&pre.text()),
+                });
+            }
+            examples.answers[index] = html::find_all(&article.children, &is_emphasized_code)
+                .last()
+                .map(|code| code.text().trim().to_string());
+        }
+        examples
+    }
+}
+
+fn is_emphasized_code(element: &Element) -> bool {
+    element.name == "code"
+        && matches!(element.children.as_slice(), [Node::Element(em)] if em.name == "em")
+}
+
+/// Drops trailing blank lines, leaving exactly one final newline. Everything
+/// else is kept as is, since trailing spaces can be part of the puzzle (e.g.
+/// column-aligned inputs).
+pub fn normalize(text: &str) -> String {
+    let mut normalized = text.trim_end_matches(['\n', '\r']).to_string();
+    normalized.push('\n');
+    normalized
+}
+
+pub fn tests_dir(year: u16) -> PathBuf {
+    config::workspace().tests_dir(year)
+}
+
+/// File name used by `load_test_input!` for the given day and optional part.
+pub fn test_file_name(day: u8, part: Option<u8>) -> String {
+    match part {
+        Some(part) => format!("{day:02}-{part}.txt"),
+        None => format!("{day:02}.txt"),
+    }
+}
+
+/// File name for the expected answer saved next to an example.
+pub fn answer_file_name(day: u8, part: u8) -> String {
+    format!("{day:02}-{part}{ANSWER_SUFFIX}")
+}
+
+/// Writes `content` to `path`, refusing to replace an existing file unless `force` is set.
+pub fn write_new_file(path: &Path, content: &str, force: bool) -> anyhow::Result<()> {
+    if path.exists() && !force {
+        anyhow::bail!(
+            "{} already exists. Use `--force` to overwrite it.",
+            path.display()
+        );
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Examples;
+    use super::normalize;
+
+    #[test]
+    fn extract_blocks_and_answers() {
+        let page = r#"<main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<pre><code>xmul(2,4)%&amp;mul[3,7]   
+</code></pre>
+<p>Only <code><em>four</em></code> are real, adding up to <code><em>161</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>173517243</code>.</p>
+<article class="day-desc"><h2>--- Part Two ---</h2>
+<pre><code>xmul(2,4)&amp;don't()
+
+</code></pre>
+<p>This time, the sum is <code><em>48</em></code>.</p>
+</article></main>"#;
+
+        let examples = Examples::from_html(page);
+        assert_eq!(examples.blocks.len(), 2);
+        assert_eq!(examples.blocks[0].part, 1);
+        assert_eq!(examples.blocks[0].text, "xmul(2,4)%&mul[3,7]   \n");
+        assert_eq!(examples.blocks[1].part, 2);
+        assert_eq!(examples.blocks[1].text, "xmul(2,4)&don't()\n");
+        assert_eq!(
+            examples.answers,
+            [Some("161".to_string()), Some("48".to_string())]
+        );
+    }
+
+    #[test]
+    fn normalize_whitespace() {
+        assert_eq!(normalize("a\nb\n\n\n"), "a\nb\n");
+        assert_eq!(normalize("  a\n b"), "  a\n b\n");
+        // Column-aligned lines keep their trailing spaces.
+        assert_eq!(
+            normalize("123 328  51 64 \n 45 64  387 23 \n*   +   *   +  \n\n"),
+            "123 328  51 64 \n 45 64  387 23 \n*   +   *   +  \n"
+        );
+    }
+}
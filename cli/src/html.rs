@@ -0,0 +1,434 @@
+//! Just enough HTML handling for the pages served by adventofcode.com: a
+//! forgiving parser into a small element tree, and a Markdown renderer for
+//! the handful of tags used in puzzle descriptions.
+
+const VOID_ELEMENTS: [&str; 6] = ["br", "hr", "img", "input", "link", "meta"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Element {
+    pub name: String,
+    pub attrs: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.attr("class")
+            .is_some_and(|classes| classes.split_whitespace().any(|c| c == class))
+    }
+
+    /// Concatenated text of all descendants, without any markup.
+    pub fn text(&self) -> String {
+        text_content(&self.children)
+    }
+}
+
+pub fn text_content(nodes: &[Node]) -> String {
+    let mut text = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(t) => text.push_str(t),
+            Node::Element(e) => text.push_str(&e.text()),
+        }
+    }
+    text
+}
+
+/// Parses `html` into a list of top-level nodes. Unknown or mismatched closing
+/// tags are ignored, and unclosed elements are closed at the end of input.
+pub fn parse(html: &str) -> Vec<Node> {
+    let mut stack: Vec<Element> = vec![Element {
+        name: String::new(),
+        attrs: Vec::new(),
+        children: Vec::new(),
+    }];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        push_text(&mut stack, &rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_ascii_lowercase();
+            if let Some(pos) = stack.iter().rposition(|e| e.name == name)
+                && pos > 0
+            {
+                while stack.len() > pos {
+                    close_top(&mut stack);
+                }
+            }
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let (name, attrs) = match tag.find(char::is_whitespace) {
+            Some(pos) => (&tag[..pos], parse_attrs(&tag[pos..])),
+            None => (tag, Vec::new()),
+        };
+        let name = name.to_ascii_lowercase();
+
+        // Script and style contents are raw text that may contain `<`.
+        let mut children = Vec::new();
+        if matches!(name.as_str(), "script" | "style") {
+            let closing = format!("</{name}");
+            let end = rest.find(&closing).unwrap_or(rest.len());
+            if end > 0 {
+                children.push(Node::Text(rest[..end].to_string()));
+            }
+            rest = &rest[end..];
+        }
+
+        let element = Element {
+            name,
+            attrs,
+            children,
+        };
+        if self_closing || VOID_ELEMENTS.contains(&element.name.as_str()) {
+            stack
+                .last_mut()
+                .unwrap()
+                .children
+                .push(Node::Element(element));
+        } else {
+            stack.push(element);
+        }
+    }
+
+    while stack.len() > 1 {
+        close_top(&mut stack);
+    }
+    stack.pop().unwrap().children
+}
+
+fn push_text(stack: &mut [Element], text: &str) {
+    if !text.is_empty() {
+        let parent = stack.last_mut().unwrap();
+        parent.children.push(Node::Text(decode_entities(text)));
+    }
+}
+
+fn close_top(stack: &mut Vec<Element>) {
+    let element = stack.pop().unwrap();
+    stack
+        .last_mut()
+        .unwrap()
+        .children
+        .push(Node::Element(element));
+}
+
+fn parse_attrs(mut s: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            break;
+        }
+        let name_end = s
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(s.len());
+        let name = s[..name_end].to_ascii_lowercase();
+        s = s[name_end..].trim_start();
+
+        let value = if let Some(after_eq) = s.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let (value, remaining) = match after_eq.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let inner = &after_eq[1..];
+                    let end = inner.find(quote).unwrap_or(inner.len());
+                    (&inner[..end], inner.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    (&after_eq[..end], &after_eq[end..])
+                }
+            };
+            s = remaining;
+            decode_entities(value)
+        } else {
+            String::new()
+        };
+        attrs.push((name, value));
+    }
+    attrs
+}
+
+pub fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let replacement = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match replacement {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Collects every element in `nodes` (recursively) matching `predicate`, in document order.
+pub fn find_all<'a>(nodes: &'a [Node], predicate: &dyn Fn(&Element) -> bool) -> Vec<&'a Element> {
+    let mut found = Vec::new();
+    for node in nodes {
+        if let Node::Element(element) = node {
+            if predicate(element) {
+                found.push(element);
+            }
+            found.extend(find_all(&element.children, predicate));
+        }
+    }
+    found
+}
+
+/// Renders block-level HTML (headings, paragraphs, lists, code blocks) as Markdown.
+pub fn to_markdown(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    render_blocks(nodes, &mut out);
+    let mut markdown = out
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    format!("{}\n", markdown.trim())
+}
+
+fn render_blocks(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => {
+                let text = collapse_whitespace(text);
+                if !text.trim().is_empty() {
+                    out.push_str(text.trim());
+                    out.push_str("\n\n");
+                }
+            }
+            Node::Element(element) => match element.name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    let title = render_inline(&element.children);
+                    let title = title.trim().trim_matches('-').trim();
+                    out.push_str(&format!("## {title}\n\n"));
+                }
+                "p" => {
+                    out.push_str(render_inline(&element.children).trim());
+                    out.push_str("\n\n");
+                }
+                "pre" => {
+                    let code = element.text();
+                    out.push_str("```\n");
+                    out.push_str(&code);
+                    if !code.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "ul" | "ol" => {
+                    for (index, item) in find_children(element, "li").enumerate() {
+                        let marker = if element.name == "ol" {
+                            format!("{}.", index + 1)
+                        } else {
+                            "-".to_string()
+                        };
+                        let text = render_inline(&item.children);
+                        out.push_str(&format!("{marker} {}\n", text.trim()));
+                    }
+                    out.push('\n');
+                }
+                _ => render_blocks(&element.children, out),
+            },
+        }
+    }
+}
+
+fn find_children<'a>(element: &'a Element, name: &'a str) -> impl Iterator<Item = &'a Element> {
+    element.children.iter().filter_map(move |node| match node {
+        Node::Element(child) if child.name == name => Some(child),
+        _ => None,
+    })
+}
+
+fn render_inline(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&collapse_whitespace(text)),
+            Node::Element(element) => match element.name.as_str() {
+                "code" => {
+                    let emphasized = element.children.len() == 1
+                        && matches!(&element.children[0], Node::Element(e) if e.name == "em");
+                    let code = element.text();
+                    if emphasized {
+                        out.push_str(&format!("**`{code}`**"));
+                    } else {
+                        out.push_str(&format!("`{code}`"));
+                    }
+                }
+                "em" | "i" => out.push_str(&format!("*{}*", render_inline(&element.children))),
+                "strong" | "b" => {
+                    out.push_str(&format!("**{}**", render_inline(&element.children)))
+                }
+                "a" => {
+                    let text = render_inline(&element.children);
+                    match element.attr("href") {
+                        Some(href) => out.push_str(&format!("[{text}]({href})")),
+                        None => out.push_str(&text),
+                    }
+                }
+                "br" => out.push_str("  \n"),
+                // Nested lists and paragraphs inside list items are flattened.
+                "p" | "ul" | "ol" | "li" => {
+                    out.push(' ');
+                    out.push_str(render_inline(&element.children).trim());
+                    out.push(' ');
+                }
+                _ => out.push_str(&render_inline(&element.children)),
+            },
+        }
+    }
+    out
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_was_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                collapsed.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            collapsed.push(c);
+            last_was_space = false;
+        }
+    }
+    collapsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Node;
+    use super::find_all;
+    use super::parse;
+    use super::to_markdown;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><head><title>Day 3</title><script>if (a < b) {}</script></head>
+<body><main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>The computer appears to be trying to run a <em>program</em>, see <a href="/2024/day/2">yesterday</a>.</p>
+<pre><code>xmul(2,4)&amp;mul[3,7]!
+do_not_mul(5,5)
+</code></pre>
+<ul>
+<li><code>mul(44,46)</code> multiplies <code>44</code> by <code>46</code>.</li>
+<li>Adding up the results produces <code><em>161</em></code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>123</code>.</p>
+</main></body></html>"#;
+
+    #[test]
+    fn parse_tree() {
+        let nodes = parse(PAGE);
+        let articles = find_all(&nodes, &|e| e.name == "article" && e.has_class("day-desc"));
+        assert_eq!(articles.len(), 1);
+
+        let blocks = find_all(&articles[0].children, &|e| e.name == "pre");
+        assert_eq!(blocks[0].text(), "xmul(2,4)&mul[3,7]!\ndo_not_mul(5,5)\n");
+
+        let scripts = find_all(&nodes, &|e| e.name == "script");
+        assert_eq!(
+            scripts[0].children,
+            vec![Node::Text("if (a < b) {}".into())]
+        );
+    }
+
+    #[test]
+    fn render_markdown() {
+        let nodes = parse(PAGE);
+        let articles = find_all(&nodes, &|e| e.name == "article");
+        let markdown = to_markdown(&[Node::Element(articles[0].clone())]);
+
+        assert_eq!(
+            markdown,
+            "## Day 3: Mull It Over\n\
+             \n\
+             The computer appears to be trying to run a *program*, see [yesterday](/2024/day/2).\n\
+             \n\
+             ```\n\
+             xmul(2,4)&mul[3,7]!\n\
+             do_not_mul(5,5)\n\
+             ```\n\
+             \n\
+             - `mul(44,46)` multiplies `44` by `46`.\n\
+             - Adding up the results produces **`161`**.\n"
+        );
+    }
+}
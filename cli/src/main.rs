@@ -1,40 +1,597 @@
+mod calendar;
+mod client;
+mod examples;
+mod html;
 mod parser;
+mod puzzle;
+mod readme;
+mod repl;
+mod report;
+mod scaffold;
+mod serve;
+mod status;
+mod submission;
+mod watch;
 
+use crate::client::AocClient;
+use crate::examples::Examples;
 use crate::parser::Cli;
 use crate::parser::Commands;
 use crate::parser::Day;
+use crate::parser::InputsCommand;
+use crate::parser::OutputFormat;
+use crate::parser::Part;
 use crate::parser::Year;
-use aoc24 as _;
-use aoc25 as _;
+use crate::puzzle::Puzzle;
+use crate::submission::History;
+use crate::submission::Outcome;
+use anyhow::Context;
 use clap::Parser;
 use colored::Colorize;
+use human_repr::HumanCount;
 use human_repr::HumanDuration;
+use runner::Filter;
+use runner::RunOptions;
+use runner::answers::AnswerBook;
+use runner::answers::RecordOutcome;
+use runner::answers::Recording;
+use runner::answers::Verdict;
+use runner::bench::BenchOptions;
+use runner::clock::Stopwatch;
+use runner::config;
+use runner::inputs;
+use runner::isolation::ChildCommand;
+use runner::last_runs::LastRuns;
+use runner::report::RunReport;
+use runner::report::Status;
+use runner::report::Summary;
+use runner::stats::Stats;
 use spinners::Spinner;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::io::IsTerminal;
+use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::SystemTime;
+use utils::memory::AllocStats;
+use utils::memory::CountingAllocator;
+use utils::solution::PhaseMemory;
+use utils::solution::PhaseTimings;
+use utils::solution::Solution;
 use utils::solution::Solver;
 
+// Counts nothing until `--memory` enables it.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
 fn collect_solvers(year: Option<Year>, day: Option<Day>) -> Vec<&'static Solver> {
-    let mut solvers: Vec<&'static Solver> = inventory::iter::<Solver>()
-        .filter(|s| match year {
-            Some(y) => y.value() == s.year,
-            None => true,
-        })
-        .filter(|s| match day {
-            Some(d) => d.value() == s.day,
-            None => true,
+    runner::solvers(&Filter {
+        year: year.map(|y| y.value()),
+        day: day.map(|d| d.value()),
+    })
+}
+
+/// Falls back to `default_year` from `aoc.toml` when `--year` is not given.
+fn year_or_default(year: Option<Year>) -> anyhow::Result<Year> {
+    match (year, config::workspace().config.default_year) {
+        (Some(year), _) => Ok(year),
+        (None, Some(default)) => {
+            Year::from_str(&default.to_string()).context("Invalid `default_year` in aoc.toml")
+        }
+        (None, None) => anyhow::bail!("Pass --year or set `default_year` in aoc.toml."),
+    }
+}
+
+fn print_report_header(report: &RunReport) {
+    println!("{}: {}", "Year".bold().green(), report.year);
+    println!("{}: {}", "Day".bold().green(), report.day);
+    println!("{}: {}", "Input".bold().green(), report.input);
+    println!();
+}
+
+fn report_status_line(report: &RunReport) -> (String, String) {
+    if report.status == Status::TimedOut {
+        return (
+            "✘".red().to_string(),
+            format!("{}.", report.error.as_deref().unwrap_or("Timed out")),
+        );
+    }
+    match report.timings() {
+        Some(timings) => (
+            "✔".green().to_string(),
+            format!(
+                "Solution found! Elapsed time: {}.",
+                timings.total.human_duration()
+            ),
+        ),
+        None => (
+            "✘".red().to_string(),
+            "An error occurred during solution.".to_string(),
+        ),
+    }
+}
+
+fn print_report_details(report: &RunReport) {
+    match (report.timings(), &report.error) {
+        (Some(timings), _) => {
+            if let Some(phases) = format_phases(&timings) {
+                println!("{}: {}", "Phases".bold().green(), phases);
+            }
+            if let Some(memory) = &timings.memory {
+                println!(
+                    "{}: {}",
+                    "Memory".bold().green(),
+                    format_alloc_stats(&memory.total)
+                );
+                if let Some(phases) = format_phase_memory(memory) {
+                    println!("{}: {}", "Memory by phase".bold().green(), phases);
+                }
+            }
+            println!();
+            println!(
+                "{}\n{}",
+                "Part one:".green().bold(),
+                report.part_one.as_deref().unwrap_or_default()
+            );
+            println!(
+                "{}\n{}",
+                "Part two:".green().bold(),
+                report.part_two.as_deref().unwrap_or_default()
+            );
+        }
+        (None, error) => eprintln!(
+            "Solver error for year {} day {}: {}",
+            report.year,
+            report.day,
+            error.as_deref().unwrap_or_default()
+        ),
+    }
+    println!();
+}
+
+fn read_and_solve(solver: &Solver, input_path: &Path) -> anyhow::Result<Solution> {
+    runner::solve(solver, &runner::read_input(input_path)?)
+}
+
+fn print_stats_row(label: &str, stats: &Stats, memory: Option<AllocStats>) {
+    print!(
+        "{:<11}{:>12}{:>12}{:>12}{:>12}{:>10}{:>10}",
+        label,
+        stats.min.human_duration().to_string(),
+        stats.median.human_duration().to_string(),
+        stats.mean.human_duration().to_string(),
+        stats.stddev.human_duration().to_string(),
+        stats.samples,
+        stats.outliers()
+    );
+    if let Some(memory) = memory {
+        print!(
+            "{:>10}{:>10}",
+            memory.allocations.human_count_bare().to_string(),
+            memory.peak_bytes.human_count_bytes().to_string()
+        );
+    }
+    println!();
+}
+
+/// Formats the phase durations of a phased solver run, e.g. "Parse: 1ms, Part one: 2ms".
+fn format_phases(timings: &PhaseTimings) -> Option<String> {
+    let phases = timings
+        .phases()
+        .into_iter()
+        .filter_map(|(name, duration)| duration.map(|d| format!("{name}: {}", d.human_duration())))
+        .collect::<Vec<_>>();
+    (!phases.is_empty()).then(|| phases.join(", "))
+}
+
+/// Formats heap usage, e.g. "1.2k allocations, 3.4MB allocated, 1.1MB peak".
+fn format_alloc_stats(stats: &AllocStats) -> String {
+    format!(
+        "{} allocations, {} allocated, {} peak",
+        stats.allocations.human_count_bare(),
+        stats.allocated_bytes.human_count_bytes(),
+        stats.peak_bytes.human_count_bytes()
+    )
+}
+
+/// Formats the peak heap usage of each phase, e.g. "Parse: 1MB peak, Part one: 2MB peak".
+fn format_phase_memory(memory: &PhaseMemory) -> Option<String> {
+    let phases = memory
+        .phases()
+        .into_iter()
+        .filter_map(|(name, stats)| {
+            stats.map(|s| {
+                format!(
+                    "{name}: {} peak ({} allocations)",
+                    s.peak_bytes.human_count_bytes(),
+                    s.allocations.human_count_bare()
+                )
+            })
         })
-        .collect();
+        .collect::<Vec<_>>();
+    (!phases.is_empty()).then(|| phases.join(", "))
+}
+
+fn colored_verdict(verdict: Verdict) -> String {
+    let symbol = format!("{verdict:<10}");
+    match verdict {
+        Verdict::Pass => symbol.green().to_string(),
+        Verdict::Fail => symbol.red().to_string(),
+        Verdict::Unknown => symbol.yellow().to_string(),
+    }
+}
+
+fn input_stored(year: u16, day: u8) -> bool {
+    let workspace = config::workspace();
+    workspace.input_path(year, day).exists() || workspace.encrypted_input_path(year, day).exists()
+}
+
+/// Downloads the puzzle input for a day, or for every unlocked day of the year
+/// when `day` is `None`. Inputs already stored are skipped unless `force` is set.
+/// With `wait`, it first waits for the day to unlock.
+fn get_input(year: u16, day: Option<u8>, force: bool, wait: bool) -> anyhow::Result<()> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => (1..=calendar::days_in_event(year))
+            .filter(|&day| calendar::is_unlocked(year, day))
+            .collect(),
+    };
+    let (stored, missing): (Vec<u8>, Vec<u8>) = days
+        .into_iter()
+        .partition(|&day| !force && input_stored(year, day));
+
+    if let Some(day) = day
+        && !stored.is_empty()
+    {
+        println!(
+            "Input for year {year}, day {day} is already stored. Use `--force` to download again."
+        );
+        return Ok(());
+    }
+    if missing.is_empty() {
+        println!("All unlocked inputs for year {year} are already stored.");
+        return Ok(());
+    }
+    if !stored.is_empty() {
+        println!("Skipping {} input(s) already stored.", stored.len());
+    }
+
+    let client = AocClient::from_env()?;
+    println!("Found session token!");
+    for day in missing {
+        if wait {
+            wait_for_unlock(year, day);
+        }
+        download_input(&client, year, day, wait)?;
+    }
+    Ok(())
+}
+
+/// Delays between attempts to download a puzzle that should have unlocked
+/// but is still reported as locked, e.g. because of clock skew.
+const UNLOCK_RETRY_DELAYS: [Duration; 4] = [
+    Duration::from_secs(1),
+    Duration::from_secs(2),
+    Duration::from_secs(4),
+    Duration::from_secs(8),
+];
+
+/// Sleeps until the puzzle for `day` unlocks, counting down on a terminal.
+fn wait_for_unlock(year: u16, day: u8) {
+    let unlock = calendar::unlock_time(year, day);
+    let Ok(remaining) = unlock.duration_since(SystemTime::now()) else {
+        return;
+    };
+    println!(
+        "Year {year}, day {day} unlocks in {}.",
+        calendar::format_countdown(remaining)
+    );
+    if !std::io::stdout().is_terminal() {
+        std::thread::sleep(remaining);
+        return;
+    }
+
+    while let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        print!("\rUnlocks in {}  ", calendar::format_countdown(remaining));
+        let _ = std::io::stdout().flush();
+        // Wake up on the second boundaries of the countdown.
+        let tick = Duration::from_nanos(u64::from(remaining.subsec_nanos()));
+        std::thread::sleep(if tick.is_zero() {
+            Duration::from_secs(1)
+        } else {
+            tick
+        });
+    }
+    println!("\rUnlocked!{}", " ".repeat(16));
+}
+
+/// Runs `download`, retrying with [`UNLOCK_RETRY_DELAYS`] while the puzzle is
+/// reported as locked if `retry` is set.
+fn retry_while_locked<T>(
+    retry: bool,
+    mut download: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let delays = if retry { &UNLOCK_RETRY_DELAYS[..] } else { &[] };
+    for delay in delays {
+        match download() {
+            Err(err) if client::is_locked_error(&err) => {
+                println!("Not unlocked yet, retrying in {}s...", delay.as_secs());
+                std::thread::sleep(*delay);
+            }
+            result => return result,
+        }
+    }
+    download()
+}
+
+/// Downloads the puzzle input for a day into the configured input directory.
+/// With `retry`, the download is retried for a few seconds while the puzzle is still locked.
+fn download_input(client: &AocClient, year: u16, day: u8, retry: bool) -> anyhow::Result<()> {
+    println!("Downloading input for year {year}, day {day}...");
+    let content = retry_while_locked(retry, || client.get_input(year, day))
+        .map_err(|e| anyhow::anyhow!("Failed to download input. {e}"))?;
+
+    // With a passphrase configured, the input only ever lands on disk encrypted.
+    if inputs::configured_cipher()?.is_some() {
+        let encrypted_file = config::workspace().encrypted_input_path(year, day);
+        println!("Writing encrypted input to {}", encrypted_file.display());
+        return inputs::write(&encrypted_file, &content);
+    }
+
+    let input_dir = config::workspace().input_dir(year);
+    let input_file = config::workspace().input_path(year, day);
+    println!("Writing input to {}", input_file.display());
+
+    std::fs::create_dir_all(&input_dir)?;
+    std::fs::write(&input_file, content)?;
+
+    Ok(())
+}
+
+/// Years covered by an `inputs` command: the given one, or every year with solvers.
+fn input_years(year: Option<Year>) -> Vec<u16> {
+    match year {
+        Some(year) => vec![year.value()],
+        None => {
+            let mut years = collect_solvers(None, None)
+                .iter()
+                .map(|s| s.year)
+                .collect::<Vec<_>>();
+            years.dedup();
+            years
+        }
+    }
+}
 
-    solvers.sort_by_key(|s| (s.year, s.day));
-    solvers
+fn input_days(year: u16, day: Option<Day>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day.value()],
+        None => (1..=calendar::days_in_event(year)).collect(),
+    }
+}
+
+/// Copies plaintext inputs into the encrypted store. Inputs whose encrypted copy
+/// already matches are left alone, so the blobs only change with their content.
+fn encrypt_inputs(year: Option<Year>, day: Option<Day>, remove: bool) -> anyhow::Result<()> {
+    let workspace = config::workspace();
+    let cipher = inputs::cipher()?;
+    let mut count = 0;
+    for year in input_years(year) {
+        for day in input_days(year, day) {
+            let plaintext = workspace.input_path(year, day);
+            let Ok(content) = std::fs::read_to_string(&plaintext) else {
+                continue;
+            };
+            let encrypted = workspace.encrypted_input_path(year, day);
+            let unchanged = encrypted.exists() && inputs::read(&encrypted)? == content;
+            if unchanged {
+                println!("{year} day {day:02}: unchanged");
+            } else {
+                std::fs::create_dir_all(workspace.encrypted_input_dir(year))?;
+                std::fs::write(&encrypted, cipher.encrypt(&content)?)?;
+                println!("{year} day {day:02}: encrypted to {}", encrypted.display());
+                count += 1;
+            }
+            if remove {
+                std::fs::remove_file(&plaintext)?;
+            }
+        }
+    }
+    println!("Encrypted {count} input(s).");
+    Ok(())
+}
+
+/// Writes encrypted inputs back out as plaintext. Plaintext files with other
+/// content are only overwritten with `force`.
+fn decrypt_inputs(year: Option<Year>, day: Option<Day>, force: bool) -> anyhow::Result<()> {
+    let workspace = config::workspace();
+    let mut count = 0;
+    let mut conflicts = Vec::new();
+    for year in input_years(year) {
+        for day in input_days(year, day) {
+            let encrypted = workspace.encrypted_input_path(year, day);
+            if !encrypted.exists() {
+                continue;
+            }
+            let content = inputs::read(&encrypted)?;
+            let plaintext = workspace.input_path(year, day);
+            match std::fs::read_to_string(&plaintext) {
+                Ok(existing) if existing == content => {
+                    println!("{year} day {day:02}: unchanged");
+                    continue;
+                }
+                Ok(_) if !force => {
+                    println!(
+                        "{year} day {day:02}: {} differs, skipping",
+                        plaintext.display()
+                    );
+                    conflicts.push(plaintext);
+                    continue;
+                }
+                _ => {}
+            }
+            std::fs::create_dir_all(workspace.input_dir(year))?;
+            std::fs::write(&plaintext, content)?;
+            println!("{year} day {day:02}: decrypted to {}", plaintext.display());
+            count += 1;
+        }
+    }
+    println!("Decrypted {count} input(s).");
+    if !conflicts.is_empty() {
+        anyhow::bail!(
+            "{} plaintext input(s) differ from the encrypted ones. Pass --force to overwrite them.",
+            conflicts.len()
+        );
+    }
+    Ok(())
+}
+
+fn print_input_status(year: Option<Year>, day: Option<Day>) -> anyhow::Result<()> {
+    let workspace = config::workspace();
+    match workspace.input_passphrase()? {
+        Some(_) => println!("Input passphrase: configured"),
+        None => println!("Input passphrase: not configured"),
+    }
+    for year in input_years(year) {
+        let mut stored = Vec::new();
+        for day in input_days(year, day) {
+            if let Some(state) = inputs::state(year, day)? {
+                stored.push((day, state));
+            }
+        }
+        println!();
+        println!(
+            "{year}: {} input(s) in {} (encrypted: {})",
+            stored.len(),
+            workspace.input_dir(year).display(),
+            workspace.encrypted_input_dir(year).display()
+        );
+        for (day, state) in stored {
+            let label = match state {
+                inputs::State::InSync | inputs::State::Encrypted => state.as_str().green(),
+                inputs::State::Differs => state.as_str().red(),
+                inputs::State::Plaintext | inputs::State::Both => state.as_str().yellow(),
+            };
+            println!("  Day {day:02}: {label}");
+        }
+    }
+    Ok(())
+}
+
+/// Downloads the puzzle description, skipping it if both parts are already stored.
+/// With `wait`, it first waits for the puzzle to unlock.
+fn get_puzzle(year: u16, day: u8, force: bool, wait: bool) -> anyhow::Result<()> {
+    let markdown_path = puzzle::markdown_path(year, day);
+    if !force
+        && let Ok(existing) = std::fs::read_to_string(&markdown_path)
+        && puzzle::has_part_two(&existing)
+    {
+        println!(
+            "Both parts are already stored in {}. Use `--force` to download again.",
+            markdown_path.display()
+        );
+        return Ok(());
+    }
+
+    let client = AocClient::from_env()?;
+    if wait {
+        wait_for_unlock(year, day);
+    }
+    println!("Downloading puzzle for year {year}, day {day}...");
+    let page = retry_while_locked(wait, || client.get_puzzle(year, day))
+        .map_err(|e| anyhow::anyhow!("Failed to download puzzle. {e}"))?;
+    let puzzle = Puzzle::from_html(&page)?;
+
+    std::fs::create_dir_all(puzzle::puzzle_dir(year))?;
+    std::fs::write(puzzle::html_path(year, day), &page)?;
+    std::fs::write(&markdown_path, &puzzle.markdown)?;
+    println!("Writing puzzle to {}", markdown_path.display());
+
+    if puzzle.parts < 2 {
+        println!("Part two is not unlocked yet. Run this again after solving part one.");
+    }
+
+    Ok(())
 }
 
-fn expected_input_path_for_solver(solver: &Solver) -> PathBuf {
-    let package_path = format!("aoc{}", solver.year % 2000);
-    PathBuf::from(format!(
-        "./{package_path}/resources/input/{:02}.txt",
-        solver.day
-    ))
+/// Lists the example blocks of a puzzle page, saving the selected one as a test input.
+fn get_examples(
+    year: u16,
+    day: u8,
+    page: Option<PathBuf>,
+    save: Option<usize>,
+    part: Option<Part>,
+    answer: bool,
+    force: bool,
+) -> anyhow::Result<()> {
+    let page_path = page.unwrap_or_else(|| puzzle::html_path(year, day));
+    let content = std::fs::read_to_string(&page_path).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to read puzzle page {}: {e}. Run `get-puzzle` first.",
+            page_path.display()
+        )
+    })?;
+    let examples = Examples::from_html(&content);
+
+    let Some(index) = save else {
+        println!("Found {} example block(s):", examples.blocks.len());
+        for (index, block) in examples.blocks.iter().enumerate() {
+            println!(
+                "{} part {}, {} line(s)",
+                format!("[{index}]").bold().green(),
+                block.part,
+                block.text.lines().count()
+            );
+            for line in block.text.lines().take(5) {
+                println!("    {line}");
+            }
+            if block.text.lines().count() > 5 {
+                println!("    ...");
+            }
+        }
+        for (part, answer) in examples.answers.iter().enumerate() {
+            if let Some(answer) = answer {
+                println!("Expected answer for part {}: {answer}", part + 1);
+            }
+        }
+        return Ok(());
+    };
+
+    let block = examples.blocks.get(index).ok_or_else(|| {
+        anyhow::anyhow!(
+            "There is no example block {index}, found {}.",
+            examples.blocks.len()
+        )
+    })?;
+    let part = part.map(|p| p.value());
+    let tests_dir = examples::tests_dir(year);
+
+    let input_path = tests_dir.join(examples::test_file_name(day, part));
+    examples::write_new_file(&input_path, &block.text, force)?;
+    println!("Writing example to {}", input_path.display());
+
+    if answer {
+        let answer_part = part.unwrap_or(block.part);
+        let Some(expected) = &examples.answers[answer_part as usize - 1] else {
+            anyhow::bail!("No emphasized answer found for part {answer_part}.");
+        };
+        let answer_path = tests_dir.join(examples::answer_file_name(day, answer_part));
+        examples::write_new_file(&answer_path, &format!("{expected}\n"), force)?;
+        println!("Writing expected answer to {}", answer_path.display());
+    }
+
+    Ok(())
+}
+
+/// Keeps `reports` for `status`. The last runs are only a convenience, so a
+/// failure to save them does not fail the command.
+fn record_last_runs(reports: &[RunReport]) {
+    if let Err(err) = runner::last_runs::record(reports) {
+        eprintln!("Warning: failed to update the last runs: {err:#}");
+    }
 }
 
 fn solver_display_id(solver: &Solver) -> String {
@@ -43,137 +600,697 @@ fn solver_display_id(solver: &Solver) -> String {
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    // `.env` may hold the session token and the input passphrase.
+    dotenvy::dotenv().ok();
+    config::init()?;
+    runner::isolation::install_panic_hook();
+    runner::isolation::set_child_command(ChildCommand {
+        program: std::env::current_exe()?,
+        args: vec!["run-one".to_string()],
+    })?;
+
+    // Colors and spinners only make sense when a person is watching.
+    let interactive = std::io::stdout().is_terminal();
+    if !interactive {
+        colored::control::set_override(false);
+    }
 
     match cli.command {
-        Commands::Run { year, day } => {
+        Commands::Run {
+            year,
+            day,
+            format,
+            jobs,
+            timeout,
+            memory,
+            input,
+            input_dir,
+        } => {
             let solvers = collect_solvers(year, day);
 
             if solvers.is_empty() {
                 anyhow::bail!("No matching solvers found for filters.");
             }
 
-            println!("{}: {}", "Matched solvers".bold().green(), solvers.len());
+            // Only runs on the default inputs show up in `status`.
+            let default_inputs = input.is_none() && input_dir.is_none();
+            let runs = match (input, input_dir) {
+                (None, None) => runner::default_inputs(&solvers),
+                (input, input_dir) => {
+                    let [solver] = solvers[..] else {
+                        anyhow::bail!(
+                            "`--input` and `--input-dir` need a single solver, but {} matched. \
+                             Pass both --year and --day.",
+                            solvers.len()
+                        );
+                    };
+                    let inputs = match (input, input_dir) {
+                        (Some(input), _) => vec![input],
+                        (None, Some(dir)) => runner::inputs_in_dir(&dir)?,
+                        (None, None) => unreachable!(),
+                    };
+                    if inputs.is_empty() {
+                        anyhow::bail!("No input files found.");
+                    }
+                    if let Some(missing) = inputs.iter().find(|path| !runner::input_exists(path)) {
+                        anyhow::bail!("Input file {} does not exist.", missing.display());
+                    }
+                    inputs.into_iter().map(|path| (solver, path)).collect()
+                }
+            };
 
-            let mut succeeded = 0usize;
-            let mut failed = 0usize;
-            let mut skipped = 0usize;
-            let mut missing_inputs: Vec<(u16, u8, PathBuf)> = Vec::new();
-
-            let mut total_solve_duration = std::time::Duration::from_secs(0);
-            // Iterate by reference so `solvers` remains available for the final summary.
-            for solver_ref in &solvers {
-                let solver = *solver_ref; // &'static Solver
-                let input_path = expected_input_path_for_solver(solver);
-
-                if !input_path.exists() {
-                    missing_inputs.push((solver.year, solver.day, input_path));
-                    skipped += 1;
-                    continue;
+            let options = RunOptions { jobs, timeout };
+            if memory {
+                if options.threads() > 1 {
+                    anyhow::bail!(
+                        "`--memory` needs `--jobs 1`, since the allocation counters are \
+                         process-wide."
+                    );
                 }
+                utils::memory::enable();
+            }
+
+            if format != OutputFormat::Text {
+                let batch = runner::run_all(&runs, &options)?;
+                if default_inputs {
+                    record_last_runs(&batch.solvers);
+                }
+                match format {
+                    OutputFormat::Json => println!("{}", report::to_json(&batch)?),
+                    OutputFormat::Csv => print!("{}", report::to_csv(&batch.solvers)),
+                    OutputFormat::Markdown => {
+                        print!("{}", report::to_markdown(&batch.solvers, &batch.summary))
+                    }
+                    OutputFormat::Text => unreachable!(),
+                }
+                return Ok(());
+            }
+
+            println!("{}: {}", "Matched solvers".bold().green(), solvers.len());
 
-                println!("{}: {}", "Year".bold().green(), solver.year);
-                println!("{}: {}", "Day".bold().green(), solver.day);
-                println!("{}: {}", "Input".bold().green(), input_path.display());
+            let (reports, summary) = if options.threads() > 1 {
+                println!("{}: {}", "Jobs".bold().green(), options.threads());
                 println!();
+                // Output is buffered until every solver is done so it stays in (year, day) order.
+                let batch = runner::run_all(&runs, &options)?;
+                for report in batch.solvers.iter().filter(|r| r.status != Status::Skipped) {
+                    print_report_header(report);
+                    let (symbol, message) = report_status_line(report);
+                    println!("{symbol} {message}");
+                    print_report_details(report);
+                }
+                (batch.solvers, batch.summary)
+            } else {
+                let stopwatch = Stopwatch::start();
+                let mut reports = Vec::new();
+                for (solver, input_path) in &runs {
+                    if !runner::input_exists(input_path) {
+                        reports.push(RunReport::skipped(solver, input_path));
+                        continue;
+                    }
 
-                let input = match std::fs::read_to_string(&input_path) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        eprintln!("Failed to read input file: {}", e);
-                        failed += 1;
+                    println!("{}: {}", "Year".bold().green(), solver.year);
+                    println!("{}: {}", "Day".bold().green(), solver.day);
+                    println!("{}: {}", "Input".bold().green(), input_path.display());
+                    println!();
+
+                    // The spinner's thread allocates, which would skew `--memory` counts.
+                    let mut spinner = (interactive && !memory)
+                        .then(|| Spinner::new(spinners::Spinners::Dots9, "Solving ...".into()));
+                    let report = runner::run_with(solver, input_path, &options);
+
+                    let (symbol, message) = report_status_line(&report);
+                    match spinner.as_mut() {
+                        Some(spinner) => spinner.stop_and_persist(&symbol, message),
+                        None => println!("{symbol} {message}"),
+                    }
+                    print_report_details(&report);
+                    reports.push(report);
+                }
+                let summary = Summary::from_reports(&reports, stopwatch.elapsed());
+                (reports, summary)
+            };
+            if default_inputs {
+                record_last_runs(&reports);
+            }
+
+            println!("===== Summary =====");
+            println!("Total matched: {}", summary.total_matched);
+            println!("Succeeded: {}", summary.succeeded);
+            println!("Failed: {}", summary.failed);
+            println!("Timed out: {}", summary.timed_out);
+            println!("Skipped (missing input): {}", summary.skipped);
+            println!(
+                "Summed solver time: {}",
+                summary.summed_solver_time().human_duration()
+            );
+            println!("Wall-clock time: {}", summary.wall_clock().human_duration());
+            if let Some(cpu_time) = summary.cpu_time() {
+                println!("CPU time: {}", cpu_time.human_duration());
+            }
+
+            if summary.skipped > 0 {
+                println!("\nMissing inputs report:");
+                for report in reports.iter().filter(|r| r.status == Status::Skipped) {
+                    println!(
+                        "- Year {} Day {} -> {}",
+                        report.year, report.day, report.input
+                    );
+                }
+            }
+
+            Ok(())
+        }
+        Commands::Bench {
+            year,
+            day,
+            warmup,
+            samples,
+            time: budget,
+            memory,
+        } => {
+            let solvers = collect_solvers(year, day);
+
+            if solvers.is_empty() {
+                anyhow::bail!("No matching solvers found for filters.");
+            }
+
+            if memory {
+                utils::memory::enable();
+            }
+
+            match budget {
+                Some(budget) => println!(
+                    "{}: {} ({} warm-up run(s), {} budget each)",
+                    "Matched solvers".bold().green(),
+                    solvers.len(),
+                    warmup,
+                    budget.human_duration()
+                ),
+                None => println!(
+                    "{}: {} ({} warm-up run(s), {} sample(s) each)",
+                    "Matched solvers".bold().green(),
+                    solvers.len(),
+                    warmup,
+                    samples
+                ),
+            }
+            println!();
+            print!(
+                "{:<6}{:<5}{:>12}{:>12}{:>12}{:>12}{:>10}{:>10}",
+                "Year", "Day", "Min", "Median", "Mean", "Std dev", "Samples", "Outliers"
+            );
+            if memory {
+                print!("{:>10}{:>10}", "Allocs", "Peak");
+            }
+            println!();
+
+            let options = BenchOptions {
+                warmup,
+                samples,
+                budget,
+            };
+            let mut skipped = Vec::new();
+            let mut failed = Vec::new();
+
+            for solver in &solvers {
+                let report = runner::bench::bench(solver, &options);
+                match report.status {
+                    Status::Skipped => {
+                        skipped.push(report.input);
+                        continue;
+                    }
+                    Status::Failed | Status::TimedOut => {
+                        println!("{:<6}{:<5}{}", report.year, report.day, "error".red());
+                        failed.push(format!(
+                            "- Year {} Day {}: {}",
+                            report.year,
+                            report.day,
+                            report.error.unwrap_or_default()
+                        ));
                         continue;
                     }
+                    Status::Succeeded => {}
+                }
+
+                let Some(stats) = report.total() else {
+                    continue;
                 };
+                let memory = report.memory();
+                print_stats_row(
+                    &format!("{:<6}{}", report.year, report.day),
+                    &stats,
+                    memory.map(|m| m.total),
+                );
+                for (index, name, stats) in report.phases() {
+                    let phase_memory = memory.and_then(|m| m.phases()[index].1);
+                    print_stats_row(&format!("  {name}"), &stats, phase_memory);
+                }
+            }
 
-                let mut spinner = Spinner::new(spinners::Spinners::Dots9, "Solving ...".into());
+            if !failed.is_empty() {
+                println!("\nSolver errors:");
+                for line in &failed {
+                    println!("{line}");
+                }
+            }
 
-                let start_time = std::time::Instant::now();
-                let result = (solver.solver)(&input);
-                let duration = start_time.elapsed();
-                total_solve_duration += duration;
-                let elapsed = duration.human_duration();
+            if !skipped.is_empty() {
+                println!("\nSkipped (missing input):");
+                for path in &skipped {
+                    println!("- {}", path.display());
+                }
+            }
 
-                let success_symbol = "✔".green().to_string();
-                let failure_symbol = "✘".red().to_string();
+            Ok(())
+        }
+        Commands::Verify { year, day, timeout } => {
+            let solvers = collect_solvers(year, day);
 
-                match result {
-                    Ok(solution) => {
-                        spinner.stop_and_persist(
-                            &success_symbol,
-                            format!("Solution found! Elapsed time: {}.", elapsed),
-                        );
-                        println!();
-                        println!("{}\n{}", "Part one:".green().bold(), solution.part_one);
-                        println!("{}\n{}", "Part two:".green().bold(), solution.part_two);
-                        succeeded += 1;
+            if solvers.is_empty() {
+                anyhow::bail!("No matching solvers found for filters.");
+            }
+
+            println!("{}: {}", "Matched solvers".bold().green(), solvers.len());
+            println!();
+            println!(
+                "{:<6}{:<5}{:<10}{:<10}",
+                "Year", "Day", "Part one", "Part two"
+            );
+
+            let options = RunOptions { jobs: 1, timeout };
+            let mut passed = 0usize;
+            let mut mismatched = 0usize;
+            let mut unknown = 0usize;
+            let mut errors: Vec<String> = Vec::new();
+            let mut mismatches: Vec<String> = Vec::new();
+
+            let verifications = runner::answers::verify(&solvers, &options)?;
+            record_last_runs(
+                &verifications
+                    .iter()
+                    .map(|v| v.report.clone())
+                    .collect::<Vec<_>>(),
+            );
+
+            for verification in verifications {
+                let report = &verification.report;
+                let row_prefix = format!("{:<6}{:<5}", report.year, report.day);
+
+                let Some((part_one, part_two)) = verification.verdicts else {
+                    if report.status == Status::Skipped {
+                        println!("{row_prefix}{}", "missing input".yellow());
+                        unknown += 2;
+                    } else {
+                        println!("{row_prefix}{}", "error".red());
+                        errors.push(format!(
+                            "- Year {} Day {}: {}",
+                            report.year,
+                            report.day,
+                            report.error.as_deref().unwrap_or_default()
+                        ));
                     }
-                    Err(err) => {
-                        spinner.stop_and_persist(
-                            &failure_symbol,
-                            "An error occurred during solution.".into(),
-                        );
-                        eprintln!(
-                            "Solver error for year {} day {}: {}",
-                            solver.year, solver.day, err
+                    continue;
+                };
+                println!(
+                    "{row_prefix}{}{}",
+                    colored_verdict(part_one),
+                    colored_verdict(part_two)
+                );
+
+                let expected = verification.expected.as_ref();
+                for (part, verdict, actual, expected) in [
+                    (
+                        1,
+                        part_one,
+                        &report.part_one,
+                        expected.and_then(|a| a.part_one.as_deref()),
+                    ),
+                    (
+                        2,
+                        part_two,
+                        &report.part_two,
+                        expected.and_then(|a| a.part_two.as_deref()),
+                    ),
+                ] {
+                    match verdict {
+                        Verdict::Pass => passed += 1,
+                        Verdict::Unknown => unknown += 1,
+                        Verdict::Fail => {
+                            mismatched += 1;
+                            mismatches.push(format!(
+                                "- Year {} Day {} Part {}: expected {}, got {}",
+                                report.year,
+                                report.day,
+                                part,
+                                expected.unwrap_or_default(),
+                                actual.as_deref().unwrap_or_default()
+                            ));
+                        }
+                    }
+                }
+            }
+
+            println!();
+            println!("===== Summary =====");
+            println!("Passed: {}", passed);
+            println!("Mismatched: {}", mismatched);
+            println!("Unknown: {}", unknown);
+            println!("Errors: {}", errors.len());
+
+            if !mismatches.is_empty() {
+                println!("\nMismatches:");
+                for line in &mismatches {
+                    println!("{line}");
+                }
+            }
+
+            if !errors.is_empty() {
+                println!("\nSolver errors:");
+                for line in &errors {
+                    println!("{line}");
+                }
+            }
+
+            if mismatched > 0 || !errors.is_empty() {
+                anyhow::bail!(
+                    "Verification failed: {} mismatched answer(s), {} solver error(s).",
+                    mismatched,
+                    errors.len()
+                );
+            }
+
+            Ok(())
+        }
+        Commands::Record {
+            year,
+            day,
+            force,
+            timeout,
+        } => {
+            let solvers = collect_solvers(year, day);
+
+            if solvers.is_empty() {
+                anyhow::bail!("No matching solvers found for filters.");
+            }
+
+            println!("{}: {}", "Matched solvers".bold().green(), solvers.len());
+
+            let options = RunOptions { jobs: 1, timeout };
+            let recorded = runner::answers::record(&solvers, force, &options)?;
+            record_last_runs(
+                &recorded
+                    .recordings
+                    .iter()
+                    .map(|r| r.report.clone())
+                    .collect::<Vec<_>>(),
+            );
+            let mut conflicts = 0usize;
+            let mut failed = 0usize;
+
+            for Recording { report, outcome } in recorded.recordings {
+                let id = format!("{} {}", report.year, report.day);
+                let (Some(outcome), Some(solution)) = (outcome, report.solution()) else {
+                    if report.status == Status::Skipped {
+                        println!("- {id}: {}", "skipped (missing input)".yellow());
+                    } else {
+                        println!(
+                            "- {id}: {} {}",
+                            "solver error:".red(),
+                            report.error.as_deref().unwrap_or_default()
                         );
                         failed += 1;
                     }
-                }
+                    continue;
+                };
 
-                println!();
+                match outcome {
+                    RecordOutcome::Added => println!(
+                        "- {id}: {} ({}, {})",
+                        "recorded".green(),
+                        solution.part_one,
+                        solution.part_two
+                    ),
+                    RecordOutcome::Unchanged => println!("- {id}: unchanged"),
+                    RecordOutcome::Overwritten(old) => println!(
+                        "- {id}: {} ({}, {}) -> ({}, {})",
+                        "overwritten".yellow(),
+                        old.part_one.unwrap_or_default(),
+                        old.part_two.unwrap_or_default(),
+                        solution.part_one,
+                        solution.part_two
+                    ),
+                    RecordOutcome::Conflict(old) => {
+                        println!(
+                            "- {id}: {} recorded ({}, {}), computed ({}, {})",
+                            "conflict:".red(),
+                            old.part_one.unwrap_or_default(),
+                            old.part_two.unwrap_or_default(),
+                            solution.part_one,
+                            solution.part_two
+                        );
+                        conflicts += 1;
+                    }
+                }
             }
 
-            let total_elapsed = total_solve_duration.human_duration();
+            for path in &recorded.written {
+                println!("Wrote answers to {}", path.display());
+            }
 
-            println!("===== Summary =====");
-            println!("Total matched: {}", solvers.len());
-            println!("Succeeded: {}", succeeded);
-            println!("Failed: {}", failed);
-            println!("Skipped (missing input): {}", skipped);
-            println!("Total solve time: {}", total_elapsed);
+            if conflicts > 0 {
+                anyhow::bail!(
+                    "{} day(s) have different recorded answers. Use `--force` to overwrite them.",
+                    conflicts
+                );
+            }
+            if failed > 0 {
+                anyhow::bail!("{} solver(s) failed.", failed);
+            }
 
-            if !missing_inputs.is_empty() {
-                println!("\nMissing inputs report:");
-                for (y, d, path) in missing_inputs {
-                    println!("- Year {} Day {} -> {}", y, d, path.display());
+            Ok(())
+        }
+        Commands::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => {
+            let year = year_or_default(year)?;
+            let answer = match answer {
+                Some(answer) => answer.trim().to_string(),
+                None => {
+                    let solver = collect_solvers(Some(year), Some(day))
+                        .into_iter()
+                        .next()
+                        .ok_or_else(|| anyhow::anyhow!("No matching solver found."))?;
+                    let input_path = runner::default_input_path(solver);
+                    println!("Computing answer from {}...", input_path.display());
+                    let solution = read_and_solve(solver, &input_path)?;
+                    if part.value() == 1 {
+                        solution.part_one
+                    } else {
+                        solution.part_two
+                    }
                 }
+            };
+
+            let (year, day, part) = (year.value(), day.value(), part.value());
+
+            let history_path = History::path_for_year(year);
+            let mut history = History::load(&history_path)?;
+            if let Some(reason) = history.check(day, part, &answer) {
+                anyhow::bail!("Refusing to submit {answer}: {reason}");
             }
 
+            let client = AocClient::from_env()?;
+            println!("Submitting {answer} for year {year}, day {day}, part {part}...");
+            let response = client.submit_answer(year, day, part, &answer)?;
+            let outcome = Outcome::from_response(&response);
+
+            let description = outcome.description();
+            match outcome {
+                Outcome::Correct => println!("{}", description.green().bold()),
+                Outcome::Unknown { .. } => println!("{}", description.yellow()),
+                _ => println!("{}", description.red()),
+            }
+
+            history.push(day, part, &answer, outcome);
+            history.save(&history_path)?;
+
             Ok(())
         }
-        Commands::GetInput { year, day } => {
-            let year = year.value();
-            let day = day.value();
+        Commands::Inputs { command } => match command {
+            InputsCommand::Encrypt { year, day, remove } => encrypt_inputs(year, day, remove),
+            InputsCommand::Decrypt { year, day, force } => decrypt_inputs(year, day, force),
+            InputsCommand::Status { year, day } => print_input_status(year, day),
+        },
+        Commands::GetInput {
+            year,
+            day,
+            force,
+            wait,
+        } => get_input(
+            year_or_default(year)?.value(),
+            day.map(|day| day.value()),
+            force,
+            wait,
+        ),
+        Commands::GetPuzzle {
+            year,
+            day,
+            force,
+            wait,
+        } => get_puzzle(year_or_default(year)?.value(), day.value(), force, wait),
+        Commands::GetExamples {
+            year,
+            day,
+            page,
+            save,
+            part,
+            answer,
+            force,
+        } => get_examples(
+            year_or_default(year)?.value(),
+            day.value(),
+            page,
+            save,
+            part,
+            answer,
+            force,
+        ),
+        Commands::New {
+            year,
+            day,
+            get_input: fetch_input,
+            examples,
+        } => {
+            let (year, day) = (year_or_default(year)?.value(), day.value());
 
-            dotenvy::dotenv().ok();
-            let session_token = std::env::var("AOC_SESSION").ok();
-            let Some(session_token) = &session_token else {
-                anyhow::bail!("Could not find AOC_SESSION in `.env`.");
-            };
-            println!("Found `AOC_SESSION` token!");
-
-            println!("Downloading input for year {year}, day {day}...");
-            let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-            let client = reqwest::blocking::Client::new();
-            let response = client
-                .get(&url)
-                .header("cookie", format!("session={}", session_token))
-                .send()?;
-            if response.status() != reqwest::StatusCode::OK {
+            let created = scaffold::new_day(year, day)?;
+            println!("Created {}", created.source.display());
+            println!("Updated {}", created.lib_rs.display());
+            println!("Created {}", created.test_input.display());
+
+            if fetch_input {
+                get_input(year, Some(day), false, false)?;
+            }
+            if examples {
+                get_puzzle(year, day, false, false)?;
+                get_examples(year, day, None, Some(0), None, true, true)?;
+            }
+
+            Ok(())
+        }
+        Commands::NewYear { year } => {
+            let created = scaffold::new_year(year.value())?;
+            println!("Created {}", created.crate_dir.display());
+            for path in created.modified {
+                println!("Updated {}", path.display());
+            }
+            println!("Scaffold its first day with `new --year {}`.", year.value());
+            Ok(())
+        }
+        Commands::RunOne {
+            year,
+            day,
+            input,
+            memory,
+        } => runner::isolation::run_one(year.value(), day.value(), &input, memory),
+        Commands::Repl { year, day, input } => {
+            let year = year_or_default(year)?;
+            let Some(solver) = runner::find_solver(year.value(), day.value()) else {
                 anyhow::bail!(
-                    "Failed to download input. Make sure your token is correct and up-to-date."
+                    "No solver found for year {} day {}.",
+                    year.value(),
+                    day.value()
+                );
+            };
+            repl::Repl::new(solver, input)?.run()
+        }
+        Commands::Watch {
+            year,
+            day,
+            no_build,
+            no_tests,
+        } => {
+            let options = watch::Options {
+                build: !no_build,
+                tests: !no_tests,
+            };
+            watch::watch(year_or_default(year)?.value(), day.value(), &options)
+        }
+        Commands::Status { year } => {
+            let years = match year {
+                Some(year) => vec![year.value()],
+                None => {
+                    let mut years = runner::solvers(&Filter::default())
+                        .iter()
+                        .map(|s| s.year)
+                        .collect::<Vec<_>>();
+                    years.dedup();
+                    years
+                }
+            };
+            let last_runs = LastRuns::load_for_workspace().unwrap_or_else(|err| {
+                eprintln!("Warning: failed to read the last runs: {err:#}");
+                LastRuns::default()
+            });
+            for year in years {
+                let book = AnswerBook::load_for_year(year)?;
+                println!(
+                    "{}",
+                    status::render(year, &status::collect(year, &book, &last_runs))
                 );
             }
-            let content = response.text()?;
+            println!("{}", status::LEGEND);
+            Ok(())
+        }
+        Commands::Serve {
+            port,
+            host,
+            jobs,
+            timeout,
+            max_input_bytes,
+        } => serve::serve(
+            &host,
+            port,
+            jobs,
+            serve::Limits {
+                max_input_bytes,
+                timeout,
+            },
+        ),
+        Commands::Readme { path } => {
+            let path = path.unwrap_or_else(|| config::workspace().path("README.md"));
+            let solvers = collect_solvers(None, None);
+            println!("Running {} solvers...", solvers.len());
+            let batch = runner::run_all(&runner::default_inputs(&solvers), &RunOptions::default())?;
+            let reports = &batch.solvers;
+            record_last_runs(reports);
 
-            let package_path = format!("aoc{}", year % 2000);
-            let input_dir = format!("./{package_path}/resources/input",);
-            let input_file = format!("{input_dir}/{day:02}.txt");
-            println!("Writing input to {input_file}");
+            let mut answers = BTreeMap::new();
+            for report in reports {
+                if let Entry::Vacant(entry) = answers.entry(report.year) {
+                    entry.insert(AnswerBook::load_for_year(report.year)?);
+                }
+            }
 
-            std::fs::create_dir_all(&input_dir)?;
-            std::fs::write(&input_file, content)?;
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let section = readme::render(reports, &answers, readme::section(&content)?);
+            let updated = readme::replace_section(&content, &section)?;
+            std::fs::write(&path, updated)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
 
+            let summary = &batch.summary;
+            println!(
+                "Updated {} ({} succeeded, {} failed, {} skipped).",
+                path.display(),
+                summary.succeeded,
+                summary.failed,
+                summary.skipped
+            );
             Ok(())
         }
         Commands::List { year, day } => {
@@ -1,7 +1,11 @@
+use crate::calendar;
 use anyhow::anyhow;
 use clap::Parser;
 use clap::Subcommand;
+use clap::ValueEnum;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about)]
@@ -21,6 +25,110 @@ pub enum Commands {
         /// Day of the puzzle (1-25). If omitted, run all days for the given year(s).
         #[arg(short, long, value_parser = Day::from_str)]
         day: Option<Day>,
+
+        /// Output format. Machine-readable formats print one record per solver.
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// Number of solvers to run concurrently. `0` uses one per available core.
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+
+        /// Kill solvers still running after this many seconds. Each solver then
+        /// runs in its own process.
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+
+        /// Count allocations and peak heap usage per solver and phase.
+        /// Needs `--jobs 1`, since the counters are process-wide.
+        #[arg(short, long)]
+        memory: bool,
+
+        /// Read the input from this file instead of `aocYY/resources/input`, or
+        /// from stdin with `-`. Requires a single solver.
+        #[arg(short, long, conflicts_with = "input_dir")]
+        input: Option<PathBuf>,
+
+        /// Run the solver on every file in this directory, e.g. teammates' inputs
+        /// or `resources/tests`. Requires a single solver.
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+    },
+
+    /// Download the puzzle description and store it as Markdown
+    GetPuzzle {
+        /// Year of the puzzle (e.g., 2023). Defaults to `default_year` from `aoc.toml`.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25)
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Day,
+
+        /// Download again even if both parts are already stored
+        #[arg(short, long)]
+        force: bool,
+
+        /// Wait for the puzzle to unlock (midnight US Eastern), then download it
+        #[arg(short, long)]
+        wait: bool,
+    },
+
+    /// List the example blocks of a saved puzzle page and save one as a test input
+    GetExamples {
+        /// Year of the puzzle (e.g., 2023). Defaults to `default_year` from `aoc.toml`.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25)
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Day,
+
+        /// Puzzle page to read. Defaults to the one saved by `get-puzzle`.
+        #[arg(long)]
+        page: Option<PathBuf>,
+
+        /// Index of the example block to save. If omitted, the blocks are only listed.
+        #[arg(short, long)]
+        save: Option<usize>,
+
+        /// Save as the input for this part only (`DD-1.txt` / `DD-2.txt`) instead of `DD.txt`
+        #[arg(short, long, value_parser = Part::from_str)]
+        part: Option<Part>,
+
+        /// Also save the emphasized expected answer next to the example
+        #[arg(short, long)]
+        answer: bool,
+
+        /// Overwrite existing test files
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Scaffold a new day: solver source file, module declaration and example input
+    New {
+        /// Year of the puzzle (e.g., 2023). Defaults to `default_year` from `aoc.toml`.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25)
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Day,
+
+        /// Also download the puzzle input
+        #[arg(long)]
+        get_input: bool,
+
+        /// Also download the puzzle and save its first example block as the test input
+        #[arg(long)]
+        examples: bool,
+    },
+
+    /// Create the crate for a new year and wire it into the workspace and the runner
+    NewYear {
+        /// Year of the puzzles (e.g., 2026)
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Year,
     },
 
     /// List matching solvers without executing them (both year and day are optional)
@@ -34,18 +142,264 @@ pub enum Commands {
         day: Option<Day>,
     },
 
-    /// Download puzzle input from adventofcode.com
-    GetInput {
-        /// Year of the puzzle (e.g., 2023)
+    /// Benchmark solutions over repeated runs (both year and day are optional)
+    Bench {
+        /// Year of the puzzle (e.g., 2023). If omitted, benchmark all years.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25). If omitted, benchmark all days for the given year(s).
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Option<Day>,
+
+        /// Untimed runs before sampling starts
+        #[arg(short, long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Number of timed runs per solver
+        #[arg(short, long, default_value_t = 10, conflicts_with = "time")]
+        samples: usize,
+
+        /// Time budget per solver in seconds; sample until it is spent instead of a fixed count
+        #[arg(short, long, value_parser = parse_seconds)]
+        time: Option<Duration>,
+
+        /// Also count allocations and peak heap usage per solver and phase
+        #[arg(short, long)]
+        memory: bool,
+    },
+
+    /// Run a single solver and print its report as JSON (used by `run --timeout`)
+    #[command(hide = true)]
+    RunOne {
         #[arg(short, long, value_parser = Year::from_str)]
         year: Year,
 
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Day,
+
+        #[arg(short, long)]
+        input: PathBuf,
+
+        #[arg(short, long)]
+        memory: bool,
+    },
+
+    /// Explore a day interactively: load the input once, then re-run parts,
+    /// switch inputs and examples and print timings
+    Repl {
+        /// Year of the puzzle (e.g., 2023). Defaults to `default_year` from `aoc.toml`.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25)
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Day,
+
+        /// Start with this file instead of `aocYY/resources/input`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+
+    /// Rebuild and re-run a day, and its tests, whenever its source file,
+    /// examples or input change
+    Watch {
+        /// Year of the puzzle (e.g., 2023). Defaults to `default_year` from `aoc.toml`.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25)
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Day,
+
+        /// Re-run the solver compiled into this binary instead of rebuilding.
+        /// Enough when only the input or examples change. Skips the tests.
+        #[arg(long)]
+        no_build: bool,
+
+        /// Do not run the day's unit tests after each build
+        #[arg(long)]
+        no_tests: bool,
+    },
+
+    /// Show a calendar of each year: which days have a solver, an input,
+    /// examples and recorded answers, and how the last run went
+    Status {
+        /// Year to show. If omitted, show every year with solvers.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+    },
+
+    /// Serve the solvers over a local HTTP JSON API
+    Serve {
+        /// Port to listen on. `0` picks a free one.
+        #[arg(short, long, default_value_t = 8080)]
+        port: u16,
+
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// Number of requests handled concurrently
+        #[arg(short, long, default_value_t = 4)]
+        jobs: usize,
+
+        /// Kill solvers still running after this many seconds
+        #[arg(short, long, value_parser = parse_seconds, default_value = "10")]
+        timeout: Duration,
+
+        /// Largest accepted input, in bytes
+        #[arg(long, default_value_t = 1 << 20)]
+        max_input_bytes: usize,
+    },
+
+    /// Run all solutions and rewrite the benchmark section of the README
+    Readme {
+        /// README to update. The table goes between the `benchmarks:start` and
+        /// `benchmarks:end` comment markers.
+        /// Defaults to the README at the workspace root.
+        #[arg(short, long)]
+        path: Option<PathBuf>,
+    },
+
+    /// Run solutions and compare their answers against the recorded ones
+    Verify {
+        /// Year of the puzzle (e.g., 2023). If omitted, verify all years.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25). If omitted, verify all days for the given year(s).
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Option<Day>,
+
+        /// Kill solvers still running after this many seconds. Each solver then
+        /// runs in its own process.
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+
+    /// Run solutions and store their answers as the recorded ones
+    Record {
+        /// Year of the puzzle (e.g., 2023). If omitted, record all years.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25). If omitted, record all days for the given year(s).
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Option<Day>,
+
+        /// Overwrite recorded answers that differ from the computed ones
+        #[arg(short, long)]
+        force: bool,
+
+        /// Kill solvers still running after this many seconds. Each solver then
+        /// runs in its own process.
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+
+    /// Submit an answer to adventofcode.com, logging it to the local guess history
+    Submit {
+        /// Year of the puzzle (e.g., 2023). Defaults to `default_year` from `aoc.toml`.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
         /// Day of the puzzle (1-25)
         #[arg(short, long, value_parser = Day::from_str)]
         day: Day,
+
+        /// Part of the puzzle (1 or 2)
+        #[arg(short, long, value_parser = Part::from_str)]
+        part: Part,
+
+        /// Answer to submit. If omitted, the solver is run and its answer is used.
+        answer: Option<String>,
+    },
+
+    /// Manage the encrypted input store
+    Inputs {
+        #[command(subcommand)]
+        command: InputsCommand,
+    },
+
+    /// Download puzzle input from adventofcode.com. With an input passphrase
+    /// configured, the input is stored encrypted.
+    GetInput {
+        /// Year of the puzzle (e.g., 2023). Defaults to `default_year` from `aoc.toml`.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25). If omitted, download every unlocked day of the
+        /// year that is not stored yet.
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Option<Day>,
+
+        /// Download again even if the input is already stored
+        #[arg(short, long)]
+        force: bool,
+
+        /// Wait for the day to unlock (midnight US Eastern), then download its input
+        #[arg(short, long, requires = "day")]
+        wait: bool,
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub enum InputsCommand {
+    /// Encrypt plaintext inputs into the encrypted store
+    Encrypt {
+        /// Year of the puzzle (e.g., 2023). If omitted, encrypt all years.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25). If omitted, encrypt all days for the given year(s).
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Option<Day>,
+
+        /// Delete the plaintext inputs once they are encrypted
+        #[arg(short, long)]
+        remove: bool,
+    },
+
+    /// Decrypt stored inputs back into plaintext files
+    Decrypt {
+        /// Year of the puzzle (e.g., 2023). If omitted, decrypt all years.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25). If omitted, decrypt all days for the given year(s).
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Option<Day>,
+
+        /// Overwrite plaintext inputs that differ from the encrypted ones
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Show which inputs are stored as plaintext, encrypted, or both
+    Status {
+        /// Year of the puzzle (e.g., 2023). If omitted, show all years.
+        #[arg(short, long, value_parser = Year::from_str)]
+        year: Option<Year>,
+
+        /// Day of the puzzle (1-25). If omitted, show all days for the given year(s).
+        #[arg(short, long, value_parser = Day::from_str)]
+        day: Option<Day>,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable report with colors and progress spinners
+    Text,
+    /// Solver records and the summary as a JSON object
+    Json,
+    /// One row per solver, without the summary
+    Csv,
+    /// Markdown table followed by the summary
+    Markdown,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Year(u16);
 
@@ -62,7 +416,7 @@ impl FromStr for Year {
         let val: u16 = s
             .parse()
             .map_err(|_| anyhow!("'{}' is not a valid u16 value", s))?;
-        if (2015..=2025).contains(&val) {
+        if (calendar::FIRST_YEAR..=calendar::LAST_YEAR).contains(&val) {
             return Ok(Year(val));
         }
         anyhow::bail!("Year out of range: {val}")
@@ -91,3 +445,35 @@ impl Day {
         self.0
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Part(u8);
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part(1)),
+            "2" => Ok(Part(2)),
+            _ => anyhow::bail!("Part must be 1 or 2, got '{s}'"),
+        }
+    }
+}
+
+impl Part {
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+/// Parses a positive number of seconds, e.g. `2.5`.
+fn parse_seconds(s: &str) -> anyhow::Result<Duration> {
+    let seconds: f64 = s
+        .parse()
+        .map_err(|_| anyhow!("'{}' is not a number of seconds", s))?;
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(duration) if !duration.is_zero() => Ok(duration),
+        _ => anyhow::bail!("Expected a positive number of seconds, got {seconds}"),
+    }
+}
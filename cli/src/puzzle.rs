@@ -0,0 +1,76 @@
+use crate::html;
+use crate::html::Node;
+use runner::config;
+use std::path::PathBuf;
+
+pub fn puzzle_dir(year: u16) -> PathBuf {
+    config::workspace().puzzles_dir(year)
+}
+
+pub fn markdown_path(year: u16, day: u8) -> PathBuf {
+    puzzle_dir(year).join(format!("{day:02}.md"))
+}
+
+/// The raw page is kept next to the Markdown so examples can be extracted from it later.
+pub fn html_path(year: u16, day: u8) -> PathBuf {
+    puzzle_dir(year).join(format!("{day:02}.html"))
+}
+
+/// A puzzle description converted to Markdown.
+pub struct Puzzle {
+    pub markdown: String,
+    /// Number of `day-desc` articles found, i.e. how many parts are unlocked.
+    pub parts: usize,
+}
+
+impl Puzzle {
+    /// Extracts the `<article class="day-desc">` sections of a puzzle page.
+    pub fn from_html(page: &str) -> anyhow::Result<Self> {
+        let nodes = html::parse(page);
+        let articles = html::find_all(&nodes, &|e| e.name == "article" && e.has_class("day-desc"));
+        if articles.is_empty() {
+            anyhow::bail!("No puzzle description found in the page.");
+        }
+
+        let markdown = articles
+            .iter()
+            .map(|article| html::to_markdown(&[Node::Element((*article).clone())]))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(Self {
+            markdown,
+            parts: articles.len(),
+        })
+    }
+}
+
+/// Whether a previously saved Markdown description already contains part two.
+pub fn has_part_two(markdown: &str) -> bool {
+    markdown.lines().any(|line| line.trim() == "## Part Two")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+    use super::has_part_two;
+
+    #[test]
+    fn extract_both_parts() {
+        let page = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>Part one.</p></article>
+<p>Your puzzle answer was <code>11</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Part two.</p></article>
+</main>"#;
+
+        let puzzle = Puzzle::from_html(page).unwrap();
+        assert_eq!(puzzle.parts, 2);
+        assert_eq!(
+            puzzle.markdown,
+            "## Day 1: Historian Hysteria\n\nPart one.\n\n## Part Two\n\nPart two.\n"
+        );
+        assert!(has_part_two(&puzzle.markdown));
+
+        assert!(Puzzle::from_html("<main><p>Nothing here</p></main>").is_err());
+    }
+}
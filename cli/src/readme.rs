@@ -0,0 +1,323 @@
+use crate::calendar;
+use human_repr::HumanDuration;
+use runner::answers::AnswerBook;
+use runner::answers::DayAnswers;
+use runner::answers::Verdict;
+use runner::report::RunReport;
+use runner::report::Status;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+pub const START_MARKER: &str = "<!-- benchmarks:start -->";
+pub const END_MARKER: &str = "<!-- benchmarks:end -->";
+
+/// Stars earned by a single day. A part counts only when the solver produced
+/// the recorded answer.
+pub fn stars(report: &RunReport, expected: Option<&DayAnswers>) -> usize {
+    let part = |actual: Option<&str>, expected: Option<&str>| {
+        report.status == Status::Succeeded
+            && Verdict::compare(expected, actual.unwrap_or_default()) == Verdict::Pass
+    };
+    let part_one = part(
+        report.part_one.as_deref(),
+        expected.and_then(|a| a.part_one.as_deref()),
+    );
+    let part_two = part(
+        report.part_two.as_deref(),
+        expected.and_then(|a| a.part_two.as_deref()),
+    );
+    usize::from(part_one) + usize::from(part_two)
+}
+
+/// Renders one table per year with the stars and timings of every solver.
+///
+/// Days that could not be checked, because the input is missing or no answer
+/// has been recorded, never lose stars: they keep the stars they had in
+/// `previous`, the current section of the README, and a skipped day keeps its
+/// whole row. The total time only covers the days timed in this run.
+pub fn render(
+    reports: &[RunReport],
+    answers: &BTreeMap<u16, AnswerBook>,
+    previous: &str,
+) -> String {
+    let previous = previous_days(previous);
+    let mut years: BTreeMap<u16, Vec<&RunReport>> = BTreeMap::new();
+    for report in reports {
+        years.entry(report.year).or_default().push(report);
+    }
+
+    let mut markdown = String::new();
+    for (year, reports) in years {
+        let book = answers.get(&year);
+        markdown.push_str(&format!("### Advent of Code {year}\n\n"));
+        markdown.push_str("| Day | Stars | Parse | Part one | Part two | Total |\n");
+        markdown.push_str("|-----|-------|-------|----------|----------|-------|\n");
+
+        let mut total_stars = 0;
+        let mut total_time = Duration::ZERO;
+        let mut timed_days = 0;
+        for report in reports {
+            let expected = book.and_then(|b| b.get(report.day));
+            let unchecked = report.status == Status::Skipped
+                || expected.is_none_or(|a| a.part_one.is_none() && a.part_two.is_none());
+            let previous = previous.get(&(year, report.day));
+            if report.status == Status::Skipped
+                && let Some(PreviousDay {
+                    row: Some(row),
+                    stars,
+                }) = previous
+            {
+                total_stars += stars;
+                markdown.push_str(row);
+                markdown.push('\n');
+                continue;
+            }
+
+            let stars = match previous {
+                Some(previous) if unchecked => previous.stars,
+                _ if unchecked => 0,
+                _ => stars(report, expected),
+            };
+            total_stars += stars;
+
+            let cells = match (report.status, report.timings()) {
+                (Status::Succeeded, Some(timings)) => {
+                    total_time += timings.total;
+                    timed_days += 1;
+                    let phase = |d: Option<Duration>| {
+                        d.map(|d| d.human_duration().to_string())
+                            .unwrap_or_else(|| "-".to_string())
+                    };
+                    [
+                        phase(timings.parse),
+                        phase(timings.part_one),
+                        phase(timings.part_two),
+                        timings.total.human_duration().to_string(),
+                    ]
+                }
+                (Status::Failed, _) => ["-".into(), "-".into(), "-".into(), "failed".into()],
+                (Status::TimedOut, _) => ["-".into(), "-".into(), "-".into(), "timed out".into()],
+                _ => ["-".into(), "-".into(), "-".into(), "no input".into()],
+            };
+            markdown.push_str(&format!(
+                "| {} | {} | {} |\n",
+                report.day,
+                "⭐".repeat(stars),
+                cells.join(" | ")
+            ));
+        }
+
+        markdown.push_str(&format!(
+            "\n**Total: {}/{} ⭐**",
+            total_stars,
+            usize::from(calendar::days_in_event(year)) * 2
+        ));
+        if timed_days > 0 {
+            markdown.push_str(&format!(
+                ", {} for the {} day(s) timed in this run",
+                total_time.human_duration(),
+                timed_days
+            ));
+        }
+        markdown.push_str("\n\n");
+    }
+    markdown
+}
+
+/// What a previous section says about a day.
+#[derive(Debug, PartialEq, Eq)]
+struct PreviousDay<'a> {
+    /// The day's row, if the section uses the layout of [`render`].
+    row: Option<&'a str>,
+    stars: usize,
+}
+
+/// The days of a previous section, by year and day. Besides the rows of
+/// [`render`], this reads the older calendar layout with five days per row:
+///
+/// ```text
+/// | Day       | 1  | 2  | 3  | 4  | 5  |
+/// | **1-5**   | ⭐⭐ | ⭐⭐ | ⭐  |    |    |
+/// ```
+fn previous_days(section: &str) -> BTreeMap<(u16, u8), PreviousDay<'_>> {
+    let mut days = BTreeMap::new();
+    let mut year = None;
+    for line in section.lines() {
+        if let Some(heading) = line.strip_prefix("### Advent of Code ") {
+            year = heading.trim().parse().ok();
+            continue;
+        }
+        let (Some(year), Some(row)) = (year, line.strip_prefix('|')) else {
+            continue;
+        };
+        let cells = row.split('|').map(str::trim).collect::<Vec<_>>();
+        let stars = |cell: &str| cell.matches('⭐').count();
+        if let Ok(day) = cells[0].parse::<u8>() {
+            let day_stars = cells.get(1).map_or(0, |cell| stars(cell));
+            days.insert(
+                (year, day),
+                PreviousDay {
+                    row: Some(line),
+                    stars: day_stars,
+                },
+            );
+        } else if let Some(first_day) = cells[0]
+            .strip_prefix("**")
+            .and_then(|range| range.split('-').next())
+            .and_then(|day| day.parse::<u8>().ok())
+        {
+            for (offset, cell) in cells[1..].iter().enumerate() {
+                if stars(cell) > 0 {
+                    let day = first_day + offset as u8;
+                    days.insert(
+                        (year, day),
+                        PreviousDay {
+                            row: None,
+                            stars: stars(cell),
+                        },
+                    );
+                }
+            }
+        }
+    }
+    days
+}
+
+/// The text between the benchmark markers in `readme`.
+pub fn section(readme: &str) -> anyhow::Result<&str> {
+    let (start, end) = markers(readme)?;
+    Ok(&readme[start + START_MARKER.len()..end])
+}
+
+/// Replaces everything between the benchmark markers in `readme` with `section`.
+pub fn replace_section(readme: &str, section: &str) -> anyhow::Result<String> {
+    let (start, end) = markers(readme)?;
+    let head = &readme[..start + START_MARKER.len()];
+    let tail = &readme[end..];
+    Ok(format!("{head}\n\n{}\n{tail}", section.trim_end()))
+}
+
+/// Byte offsets of the start and end markers.
+fn markers(readme: &str) -> anyhow::Result<(usize, usize)> {
+    let (Some(start), Some(end)) = (readme.find(START_MARKER), readme.find(END_MARKER)) else {
+        anyhow::bail!(
+            "README is missing the `{START_MARKER}` and `{END_MARKER}` markers around the table \
+             section."
+        );
+    };
+    if end < start {
+        anyhow::bail!("`{END_MARKER}` appears before `{START_MARKER}` in the README.");
+    }
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use super::replace_section;
+    use super::section;
+    use super::stars;
+    use runner::answers::AnswerBook;
+    use runner::answers::DayAnswers;
+    use runner::report::RunReport;
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use std::time::Duration;
+    use utils::solution::PhaseTimings;
+    use utils::solution::Solution;
+    use utils::solution::Solver;
+
+    fn dummy(_: &str) -> anyhow::Result<Solution> {
+        unreachable!()
+    }
+
+    fn succeeded(day: u8, part_one: &str) -> RunReport {
+        let timings = PhaseTimings {
+            total: Duration::from_millis(2),
+            ..PhaseTimings::default()
+        };
+        let solution = Solution {
+            part_one: part_one.into(),
+            part_two: "2".into(),
+        };
+        RunReport::succeeded(
+            &Solver::new(2024, day, dummy),
+            Path::new("in"),
+            solution,
+            timings,
+        )
+    }
+
+    #[test]
+    fn count_stars() {
+        let recorded = DayAnswers {
+            part_one: Some("1".into()),
+            part_two: None,
+        };
+        assert_eq!(stars(&succeeded(1, "1"), Some(&recorded)), 1);
+        assert_eq!(stars(&succeeded(1, "9"), Some(&recorded)), 0);
+        assert_eq!(stars(&succeeded(1, "1"), None), 0);
+
+        let skipped = RunReport::skipped(&Solver::new(2024, 1, dummy), Path::new("in"));
+        assert_eq!(stars(&skipped, Some(&recorded)), 0);
+    }
+
+    #[test]
+    fn render_tables() {
+        let reports = [succeeded(1, "1"), succeeded(2, "1")];
+        let mut book = AnswerBook::default();
+        book.record(
+            1,
+            &Solution {
+                part_one: "1".into(),
+                part_two: "2".into(),
+            },
+            false,
+        );
+        let markdown = render(&reports, &BTreeMap::from([(2024, book)]), "");
+        assert!(markdown.starts_with("### Advent of Code 2024\n\n| Day | Stars |"));
+        assert!(markdown.contains("| 1 | ⭐⭐ | - | - | - | 2ms |\n"));
+        assert!(markdown.contains("| 2 |  | - | - | - | 2ms |\n"));
+        assert!(markdown.contains("**Total: 2/50 ⭐**, 4ms for the 2 day(s) timed in this run"));
+    }
+
+    #[test]
+    fn keep_stars_of_unchecked_days() {
+        let previous = "\n### Advent of Code 2024\n\n| Day | Stars | Parse | Part one | Part two \
+                        | Total |\n|-----|-------|-------|----------|----------|-------|\n\
+                        | 1 | ⭐ | - | - | - | 1ms |\n| 2 | ⭐⭐ | - | - | - | 5ms |\n";
+        let skipped = RunReport::skipped(&Solver::new(2024, 1, dummy), Path::new("in"));
+        let reports = [skipped, succeeded(2, "1"), succeeded(3, "1")];
+        let markdown = render(&reports, &BTreeMap::new(), previous);
+        assert!(markdown.contains("| 1 | ⭐ | - | - | - | 1ms |\n"));
+        assert!(markdown.contains("| 2 | ⭐⭐ | - | - | - | 2ms |\n"));
+        assert!(markdown.contains("| 3 |  | - | - | - | 2ms |\n"));
+        assert!(markdown.contains("**Total: 3/50 ⭐**, 4ms for the 2 day(s) timed in this run"));
+    }
+
+    #[test]
+    fn keep_stars_from_calendar_layout() {
+        let previous = "\n### Advent of Code 2024\n\n| Day       | 1  | 2  | 3  | 4  | 5  |\n\
+                        |-----------|----|----|----|----|----|\n\
+                        | **1-5**   | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ |\n\
+                        | **6-10**  | ⭐⭐ | ⭐  |    |    |    |\n\n**Total: 13/50 ⭐**\n";
+        let skipped = |day| RunReport::skipped(&Solver::new(2024, day, dummy), Path::new("in"));
+        let reports = [skipped(1), succeeded(7, "1"), skipped(8)];
+        let markdown = render(&reports, &BTreeMap::new(), previous);
+        assert!(markdown.contains("| 1 | ⭐⭐ | - | - | - | no input |\n"));
+        assert!(markdown.contains("| 7 | ⭐ | - | - | - | 2ms |\n"));
+        assert!(markdown.contains("| 8 |  | - | - | - | no input |\n"));
+        assert!(markdown.contains("**Total: 3/50 ⭐**, 2ms for the 1 day(s) timed in this run"));
+    }
+
+    #[test]
+    fn replace_marked_section() {
+        let readme = "# Title\n<!-- benchmarks:start -->\nold\n<!-- benchmarks:end -->\nrest\n";
+        assert_eq!(
+            replace_section(readme, "new\n\n").unwrap(),
+            "# Title\n<!-- benchmarks:start -->\n\nnew\n<!-- benchmarks:end -->\nrest\n"
+        );
+        assert!(replace_section("# Title\n", "new").is_err());
+        assert_eq!(section(readme).unwrap(), "\nold\n");
+    }
+}
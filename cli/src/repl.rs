@@ -0,0 +1,336 @@
+use crate::examples;
+use crate::format_phases;
+use colored::Colorize;
+use human_repr::HumanDuration;
+use runner::STDIN_INPUT;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use utils::solution::Solver;
+
+const HELP: &str = "\
+Commands:
+  run, r             Solve both parts
+  part <1|2>, 1, 2   Solve only one part. Solvers that solve both parts in
+                     one call cannot run a single part.
+  input [PATH]       Load PATH, or the puzzle input again
+  example [N]        Load example N from the tests directory, or toggle
+                     between the puzzle input and the first example
+  reload             Read the current file again
+  timings [on|off]   Show or hide timings (toggles without an argument)
+  info               Show which input is loaded
+  help               Show this help
+  quit, q            Exit";
+
+/// A line typed at the prompt.
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run,
+    Part(u8),
+    Input(Option<PathBuf>),
+    Example(Option<u8>),
+    Reload,
+    Timings(Option<bool>),
+    Info,
+    Help,
+    Quit,
+}
+
+impl Command {
+    /// Parses a line, returning `None` for a blank one.
+    fn parse(line: &str) -> anyhow::Result<Option<Self>> {
+        let mut words = line.split_whitespace();
+        let Some(name) = words.next() else {
+            return Ok(None);
+        };
+        let argument = words.next();
+        if words.next().is_some() {
+            anyhow::bail!("Too many arguments to `{name}`");
+        }
+        let command = match (name, argument) {
+            ("run" | "r", None) => Self::Run,
+            ("1" | "2", None) => Self::Part(parse_part(name)?),
+            ("part" | "p", Some(part)) => Self::Part(parse_part(part)?),
+            ("input" | "i", path) => Self::Input(path.map(PathBuf::from)),
+            ("example" | "e", number) => Self::Example(number.map(parse_part).transpose()?),
+            ("reload", None) => Self::Reload,
+            ("timings" | "t", None) => Self::Timings(None),
+            ("timings" | "t", Some("on")) => Self::Timings(Some(true)),
+            ("timings" | "t", Some("off")) => Self::Timings(Some(false)),
+            ("info", None) => Self::Info,
+            ("help" | "h" | "?", None) => Self::Help,
+            ("quit" | "exit" | "q", None) => Self::Quit,
+            _ => anyhow::bail!("Unknown command `{}`. Type `help` for a list.", line.trim()),
+        };
+        Ok(Some(command))
+    }
+}
+
+fn parse_part(part: &str) -> anyhow::Result<u8> {
+    match part.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => anyhow::bail!("Expected part 1 or 2, got `{part}`"),
+    }
+}
+
+/// Where the loaded input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    Input(PathBuf),
+    /// An example from the tests directory, with the part its expected answer belongs to.
+    Example {
+        path: PathBuf,
+        part: Option<u8>,
+    },
+}
+
+impl Source {
+    fn path(&self) -> &Path {
+        match self {
+            Self::Input(path) | Self::Example { path, .. } => path,
+        }
+    }
+}
+
+struct Loaded {
+    source: Source,
+    input: String,
+}
+
+/// Interactive session for a single solver. The input is read once and kept
+/// in memory, so re-running a part only costs the solve itself.
+pub struct Repl {
+    solver: &'static Solver,
+    /// The puzzle input: `--input`, or the default input of the day.
+    input_path: PathBuf,
+    loaded: Option<Loaded>,
+    timings: bool,
+}
+
+impl Repl {
+    pub fn new(solver: &'static Solver, input_path: Option<PathBuf>) -> anyhow::Result<Self> {
+        let input_path = input_path.unwrap_or_else(|| runner::default_input_path(solver));
+        if input_path == Path::new(STDIN_INPUT) {
+            anyhow::bail!("The REPL reads commands from stdin, so its input must be a file.");
+        }
+        Ok(Self {
+            solver,
+            input_path,
+            loaded: None,
+            timings: true,
+        })
+    }
+
+    /// Reads commands from stdin until `quit` or the end of input.
+    pub fn run(mut self) -> anyhow::Result<()> {
+        println!(
+            "Solver for year {} day {}. Type `help` for a list of commands.",
+            self.solver.year, self.solver.day
+        );
+        let source = Source::Input(self.input_path.clone());
+        self.report(|repl| repl.load(source));
+
+        let mut lines = std::io::stdin().lock().lines();
+        loop {
+            print!("{}/{:02}> ", self.solver.year, self.solver.day);
+            std::io::stdout().flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                println!();
+                return Ok(());
+            };
+            match Command::parse(&line) {
+                Ok(Some(Command::Quit)) => return Ok(()),
+                Ok(Some(command)) => self.report(|repl| repl.execute(command)),
+                Ok(None) => {}
+                Err(err) => eprintln!("{err}"),
+            }
+        }
+    }
+
+    fn report(&mut self, action: impl FnOnce(&mut Self) -> anyhow::Result<()>) {
+        if let Err(err) = action(self) {
+            eprintln!("{} {err:#}", "✘".red());
+        }
+    }
+
+    fn execute(&mut self, command: Command) -> anyhow::Result<()> {
+        match command {
+            Command::Run => self.solve(None),
+            Command::Part(part) => self.solve(Some(part)),
+            Command::Input(path) => {
+                let path = path.unwrap_or_else(|| self.input_path.clone());
+                self.load(Source::Input(path))
+            }
+            Command::Example(part) => match (part, &self.loaded) {
+                (
+                    None,
+                    Some(Loaded {
+                        source: Source::Example { .. },
+                        ..
+                    }),
+                ) => self.load(Source::Input(self.input_path.clone())),
+                _ => self.load(self.example(part)),
+            },
+            Command::Reload => match &self.loaded {
+                Some(loaded) => self.load(loaded.source.clone()),
+                None => self.load(Source::Input(self.input_path.clone())),
+            },
+            Command::Timings(enabled) => {
+                self.timings = enabled.unwrap_or(!self.timings);
+                println!("Timings {}.", if self.timings { "on" } else { "off" });
+                Ok(())
+            }
+            Command::Info => {
+                match &self.loaded {
+                    Some(loaded) => self.print_loaded(loaded),
+                    None => println!("No input loaded."),
+                }
+                Ok(())
+            }
+            Command::Help => {
+                println!("{HELP}");
+                Ok(())
+            }
+            Command::Quit => Ok(()),
+        }
+    }
+
+    /// The example for `part`, or the one `load_test_input!` reads by default:
+    /// `DD.txt`, falling back to `DD-1.txt`.
+    fn example(&self, part: Option<u8>) -> Source {
+        let dir = examples::tests_dir(self.solver.year);
+        let day = self.solver.day;
+        let path = dir.join(examples::test_file_name(day, part));
+        match part {
+            None if !path.exists() => Source::Example {
+                path: dir.join(examples::test_file_name(day, Some(1))),
+                part: Some(1),
+            },
+            part => Source::Example { path, part },
+        }
+    }
+
+    fn load(&mut self, source: Source) -> anyhow::Result<()> {
+        let input = runner::read_input(source.path())?;
+        let loaded = Loaded { source, input };
+        self.print_loaded(&loaded);
+        self.loaded = Some(loaded);
+        Ok(())
+    }
+
+    fn print_loaded(&self, loaded: &Loaded) {
+        let kind = match loaded.source {
+            Source::Input(_) => "input",
+            Source::Example { .. } => "example",
+        };
+        println!(
+            "Loaded {kind} {} ({} lines).",
+            loaded.source.path().display(),
+            loaded.input.lines().count()
+        );
+    }
+
+    /// Solves the loaded input and prints `part`, or both parts.
+    fn solve(&self, part: Option<u8>) -> anyhow::Result<()> {
+        let Some(loaded) = &self.loaded else {
+            anyhow::bail!("No input loaded. Use `input PATH` or `example`.");
+        };
+        let (answers, timings) = match part {
+            None => {
+                let (solution, timings) =
+                    runner::isolation::catch_panic(|| self.solver.solve_timed(&loaded.input))?;
+                (
+                    vec![(1, solution.part_one), (2, solution.part_two)],
+                    timings,
+                )
+            }
+            Some(part) => {
+                let (answer, timings) = runner::isolation::catch_panic(|| {
+                    self.solver
+                        .solve_part(&loaded.input, part)
+                        .unwrap_or_else(|| {
+                            anyhow::bail!(
+                                "Year {} day {} solves both parts in one call, so it cannot run \
+                                 a single part. Use `run` instead.",
+                                self.solver.year,
+                                self.solver.day
+                            )
+                        })
+                })?;
+                (vec![(part, answer)], timings)
+            }
+        };
+
+        for (number, answer) in answers {
+            let label = if number == 1 {
+                "Part one:"
+            } else {
+                "Part two:"
+            };
+            let check = self.check(loaded, number, &answer);
+            println!("{}{check}\n{answer}", label.green().bold());
+        }
+        if self.timings {
+            let elapsed = timings.total.human_duration();
+            match format_phases(&timings) {
+                Some(phases) => println!("{}: {elapsed} ({phases})", "Elapsed".bold().green()),
+                None => println!("{}: {elapsed}", "Elapsed".bold().green()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Compares an answer for an example with the expected one saved next to it.
+    fn check(&self, loaded: &Loaded, part: u8, answer: &str) -> String {
+        if !matches!(loaded.source, Source::Example { part: Some(example), .. } if example == part)
+        {
+            return String::new();
+        }
+        let expected_path = examples::tests_dir(self.solver.year)
+            .join(examples::answer_file_name(self.solver.day, part));
+        let Ok(expected) = std::fs::read_to_string(expected_path) else {
+            return String::new();
+        };
+        if expected.trim() == answer.trim() {
+            format!(" {}", "✔".green())
+        } else {
+            format!(" {} expected {}", "✘".red(), expected.trim())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use std::path::PathBuf;
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(Command::parse("  ").unwrap(), None);
+        assert_eq!(Command::parse("run").unwrap(), Some(Command::Run));
+        assert_eq!(Command::parse("2").unwrap(), Some(Command::Part(2)));
+        assert_eq!(Command::parse("part 1").unwrap(), Some(Command::Part(1)));
+        assert_eq!(
+            Command::parse("input other.txt").unwrap(),
+            Some(Command::Input(Some(PathBuf::from("other.txt"))))
+        );
+        assert_eq!(
+            Command::parse("example").unwrap(),
+            Some(Command::Example(None))
+        );
+        assert_eq!(
+            Command::parse("e 2").unwrap(),
+            Some(Command::Example(Some(2)))
+        );
+        assert_eq!(
+            Command::parse("timings off").unwrap(),
+            Some(Command::Timings(Some(false)))
+        );
+
+        assert!(Command::parse("part 3").is_err());
+        assert!(Command::parse("run now").is_err());
+        assert!(Command::parse("timings maybe").is_err());
+        assert!(Command::parse("frobnicate").is_err());
+    }
+}
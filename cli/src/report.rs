@@ -0,0 +1,173 @@
+use human_repr::HumanDuration;
+use runner::report::AllocUsage;
+use runner::report::BatchReport;
+use runner::report::RunReport;
+use runner::report::Summary;
+
+pub fn to_json(report: &BatchReport) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(report)?)
+}
+
+/// One CSV row per solver. The summary is left out since it does not fit the columns.
+pub fn to_csv(reports: &[RunReport]) -> String {
+    let mut csv = String::from(
+        "year,day,status,part_one,part_two,total_ms,parse_ms,part_one_ms,part_two_ms,allocations,\
+         allocated_bytes,peak_bytes,error,input\n",
+    );
+    for report in reports {
+        let ms = |value: Option<f64>| value.map(|v| format!("{v:.3}")).unwrap_or_default();
+        let memory = |field: fn(&AllocUsage) -> u64| {
+            report
+                .memory
+                .map(|m| field(&m.total).to_string())
+                .unwrap_or_default()
+        };
+        let fields = [
+            report.year.to_string(),
+            report.day.to_string(),
+            report.status.as_str().to_string(),
+            report.part_one.clone().unwrap_or_default(),
+            report.part_two.clone().unwrap_or_default(),
+            ms(report.durations.total_ms),
+            ms(report.durations.parse_ms),
+            ms(report.durations.part_one_ms),
+            ms(report.durations.part_two_ms),
+            memory(|m| m.allocations),
+            memory(|m| m.allocated_bytes),
+            memory(|m| m.peak_bytes),
+            report.error.clone().unwrap_or_default(),
+            report.input.clone(),
+        ];
+        let row = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&row);
+        csv.push('\n');
+    }
+    csv
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn to_markdown(reports: &[RunReport], summary: &Summary) -> String {
+    let mut markdown = String::from(
+        "| Year | Day | Status | Part one | Part two | Time | Error |\n\
+         |------|-----|--------|----------|----------|------|-------|\n",
+    );
+    for report in reports {
+        let time = report
+            .timings()
+            .map(|t| t.total.human_duration().to_string())
+            .unwrap_or_default();
+        let cell = |value: Option<&str>| value.unwrap_or_default().replace('|', "\\|");
+        markdown.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
+            report.year,
+            report.day,
+            report.status.as_str(),
+            cell(report.part_one.as_deref()),
+            cell(report.part_two.as_deref()),
+            time,
+            cell(report.error.as_deref()),
+        ));
+    }
+
+    markdown.push_str(&format!(
+        "\n**Summary:** {} matched, {} succeeded, {} failed, {} timed out, {} skipped \
+         (missing input). \
+         Summed solver time: {} (wall-clock: {}{}).\n",
+        summary.total_matched,
+        summary.succeeded,
+        summary.failed,
+        summary.timed_out,
+        summary.skipped,
+        summary.summed_solver_time().human_duration(),
+        summary.wall_clock().human_duration(),
+        summary
+            .cpu_time()
+            .map(|cpu_time| format!(", CPU: {}", cpu_time.human_duration()))
+            .unwrap_or_default()
+    ));
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csv_field;
+    use super::to_csv;
+    use super::to_json;
+    use runner::clock::Elapsed;
+    use runner::report::BatchReport;
+    use runner::report::RunReport;
+    use std::path::Path;
+    use std::time::Duration;
+    use utils::memory::AllocStats;
+    use utils::solution::PhaseMemory;
+    use utils::solution::PhaseTimings;
+    use utils::solution::Solution;
+    use utils::solution::Solver;
+
+    fn dummy(_: &str) -> anyhow::Result<Solution> {
+        unreachable!()
+    }
+
+    fn reports() -> Vec<RunReport> {
+        let solver = Solver::new(2024, 1, dummy);
+        let timings = PhaseTimings {
+            parse: Some(Duration::from_millis(1)),
+            part_one: Some(Duration::from_millis(2)),
+            part_two: Some(Duration::from_millis(3)),
+            total: Duration::from_millis(6),
+            memory: Some(PhaseMemory {
+                total: AllocStats {
+                    allocations: 3,
+                    allocated_bytes: 1024,
+                    peak_bytes: 512,
+                },
+                ..PhaseMemory::default()
+            }),
+        };
+        let solution = Solution {
+            part_one: "11".into(),
+            part_two: "a,b".into(),
+        };
+        vec![
+            RunReport::succeeded(&solver, Path::new("in/01.txt"), solution, timings),
+            RunReport::failed(&solver, Path::new("in/01.txt"), &anyhow::anyhow!("boom")),
+            RunReport::skipped(&solver, Path::new("in/02.txt")),
+        ]
+    }
+
+    #[test]
+    fn render_csv() {
+        let csv = to_csv(&reports());
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[1],
+            "2024,1,succeeded,11,\"a,b\",6.000,1.000,2.000,3.000,3,1024,512,,in/01.txt"
+        );
+        assert_eq!(lines[2], "2024,1,failed,,,,,,,,,,boom,in/01.txt");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn render_json() {
+        let json = to_json(&BatchReport::new(reports(), Elapsed::default())).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["solvers"][0]["status"], "succeeded");
+        assert_eq!(value["solvers"][0]["durations"]["part_two_ms"], 3.0);
+        assert_eq!(value["solvers"][0]["memory"]["total"]["peak_bytes"], 512);
+        assert_eq!(value["solvers"][1]["error"], "boom");
+        assert!(value["solvers"][1]["memory"].is_null());
+        assert_eq!(value["summary"]["skipped"], 1);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::examples;
+use anyhow::Context;
+use runner::config;
+use std::path::PathBuf;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const YEAR_MANIFEST_TEMPLATE: &str = include_str!("../templates/year.Cargo.toml.tmpl");
+
+pub fn crate_name(year: u16) -> String {
+    format!("aoc{}", year % 2000)
+}
+
+pub fn crate_dir(year: u16) -> PathBuf {
+    config::workspace().path(crate_name(year))
+}
+
+/// Module name of a day in its year crate, e.g. `day01`.
+pub fn day_module(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+pub fn day_source_path(year: u16, day: u8) -> PathBuf {
+    crate_dir(year)
+        .join("src")
+        .join(format!("{}.rs", day_module(day)))
+}
+
+pub fn render_day(year: u16, day: u8) -> String {
+    DAY_TEMPLATE
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Inserts `mod dayXX;` into the contents of a year's `lib.rs`, keeping the
+/// declarations sorted. Returns `None` if the module is already declared.
+pub fn add_day_module(lib_rs: &str, day: u8) -> Option<String> {
+    let declaration = format!("mod {};", day_module(day));
+    let mut lines: Vec<&str> = lib_rs.lines().collect();
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return None;
+    }
+
+    let is_day_module = |line: &str| line.starts_with("mod day") && line.ends_with(';');
+    let position = lines
+        .iter()
+        .position(|line| is_day_module(line) && *line > declaration.as_str())
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| is_day_module(line))
+                .map(|pos| pos + 1)
+        })
+        .unwrap_or(lines.len());
+    lines.insert(position, &declaration);
+
+    let mut updated = lines.join("\n");
+    if lib_rs.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+/// Files created or modified when scaffolding a day.
+pub struct NewDay {
+    pub source: PathBuf,
+    pub lib_rs: PathBuf,
+    pub test_input: PathBuf,
+}
+
+/// Creates `dayXX.rs`, declares it in `lib.rs` and creates an empty example
+/// input. Nothing is written if any of the new files already exists.
+pub fn new_day(year: u16, day: u8) -> anyhow::Result<NewDay> {
+    let crate_dir = crate_dir(year);
+    let lib_rs = crate_dir.join("src").join("lib.rs");
+    if !lib_rs.exists() {
+        anyhow::bail!(
+            "{} does not exist. Create the year crate first.",
+            lib_rs.display()
+        );
+    }
+
+    let source = day_source_path(year, day);
+    let test_input = examples::tests_dir(year).join(examples::test_file_name(day, None));
+    for path in [&source, &test_input] {
+        if path.exists() {
+            anyhow::bail!("{} already exists.", path.display());
+        }
+    }
+
+    let lib_content = std::fs::read_to_string(&lib_rs)
+        .with_context(|| format!("Failed to read {}", lib_rs.display()))?;
+    let Some(lib_content) = add_day_module(&lib_content, day) else {
+        anyhow::bail!("day{day:02} is already declared in {}.", lib_rs.display());
+    };
+
+    std::fs::write(&source, render_day(year, day))?;
+    std::fs::write(&lib_rs, lib_content)?;
+    if let Some(parent) = test_input.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&test_input, "")?;
+
+    Ok(NewDay {
+        source,
+        lib_rs,
+        test_input,
+    })
+}
+
+/// Inserts `line` into the contiguous block of lines starting at `start`,
+/// keeping the block sorted. Returns `None` if the line is already present.
+fn insert_sorted(content: &str, start: usize, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let end = lines[start..]
+        .iter()
+        .position(|l| {
+            let l = l.trim();
+            l.is_empty() || l.starts_with('[') || l.starts_with(']')
+        })
+        .map_or(lines.len(), |pos| start + pos);
+
+    if lines[start..end].iter().any(|l| l.trim() == line.trim()) {
+        return None;
+    }
+    let position = lines[start..end]
+        .iter()
+        .position(|l| l.trim() > line.trim())
+        .map_or(end, |pos| start + pos);
+    lines.insert(position, line);
+
+    let mut updated = lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+fn line_index(content: &str, predicate: impl Fn(&str) -> bool) -> Option<usize> {
+    content.lines().position(|l| predicate(l.trim()))
+}
+
+/// Adds `name` to the `members` array of the workspace manifest.
+pub fn add_workspace_member(manifest: &str, name: &str) -> anyhow::Result<Option<String>> {
+    let start = line_index(manifest, |l| l.starts_with("members = ["))
+        .ok_or_else(|| anyhow::anyhow!("No `members` array in the workspace manifest."))?;
+    Ok(insert_sorted(
+        manifest,
+        start + 1,
+        &format!("    \"{name}\","),
+    ))
+}
+
+/// Adds `dependency` as the first line of the `[section]` table, keeping it sorted.
+pub fn add_dependency(
+    manifest: &str,
+    section: &str,
+    dependency: &str,
+) -> anyhow::Result<Option<String>> {
+    let header = format!("[{section}]");
+    let start = line_index(manifest, |l| l == header)
+        .ok_or_else(|| anyhow::anyhow!("No `{header}` table in the manifest."))?;
+    Ok(insert_sorted(manifest, start + 1, dependency))
+}
+
+/// Adds `use <name> as _;` next to the other year crates linked into the runner.
+pub fn add_crate_import(lib_rs: &str, name: &str) -> anyhow::Result<Option<String>> {
+    // `use crate::...` sorts first, the external crates after it are alphabetical.
+    let start = line_index(lib_rs, |l| {
+        l.starts_with("use ") && !l.starts_with("use crate::")
+    })
+    .ok_or_else(|| anyhow::anyhow!("No `use` declarations in the runner library."))?;
+    Ok(insert_sorted(lib_rs, start, &format!("use {name} as _;")))
+}
+
+/// Files created or modified when bootstrapping a year.
+pub struct NewYear {
+    pub crate_dir: PathBuf,
+    pub modified: Vec<PathBuf>,
+}
+
+/// Creates the `aocYY` crate and wires it into the workspace manifest, the
+/// runner manifest and the runner library, which links the solvers.
+pub fn new_year(year: u16) -> anyhow::Result<NewYear> {
+    let name = crate_name(year);
+    let crate_dir = crate_dir(year);
+    if crate_dir.exists() {
+        anyhow::bail!("{} already exists.", crate_dir.display());
+    }
+
+    let workspace_manifest = config::workspace().path("Cargo.toml");
+    let runner_manifest = config::workspace().path("runner/Cargo.toml");
+    let runner_lib = config::workspace().path("runner/src/lib.rs");
+
+    let read = |path: &PathBuf| {
+        std::fs::read_to_string(path).with_context(|| {
+            format!(
+                "Failed to read {}. Run this from inside the workspace.",
+                path.display()
+            )
+        })
+    };
+
+    // Compute every edit before touching the file system, so a failure leaves nothing half done.
+    let mut edits = Vec::new();
+    let workspace = read(&workspace_manifest)?;
+    let workspace = add_workspace_member(&workspace, &name)?.unwrap_or(workspace);
+    let workspace = add_dependency(
+        &workspace,
+        "workspace.dependencies",
+        &format!("{name} = {{ path = \"{name}\" }}"),
+    )?
+    .unwrap_or(workspace);
+    edits.push((workspace_manifest, workspace));
+
+    let runner = read(&runner_manifest)?;
+    let runner = add_dependency(
+        &runner,
+        "dependencies",
+        &format!("{name} = {{ workspace = true }}"),
+    )?
+    .unwrap_or(runner);
+    edits.push((runner_manifest, runner));
+
+    let lib_rs = read(&runner_lib)?;
+    let lib_rs = add_crate_import(&lib_rs, &name)?.unwrap_or(lib_rs);
+    edits.push((runner_lib, lib_rs));
+
+    std::fs::create_dir_all(crate_dir.join("src"))?;
+    std::fs::create_dir_all(crate_dir.join("resources").join("tests"))?;
+    std::fs::create_dir_all(crate_dir.join("resources").join("input"))?;
+    std::fs::write(
+        crate_dir.join("Cargo.toml"),
+        YEAR_MANIFEST_TEMPLATE.replace("{{crate}}", &name),
+    )?;
+    std::fs::write(crate_dir.join("src").join("lib.rs"), "")?;
+    // Puzzle inputs must not be published.
+    std::fs::write(crate_dir.join("resources").join(".gitignore"), "input/")?;
+
+    let mut modified = Vec::new();
+    for (path, content) in edits {
+        std::fs::write(&path, content)?;
+        modified.push(path);
+    }
+
+    Ok(NewYear {
+        crate_dir,
+        modified,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_crate_import;
+    use super::add_day_module;
+    use super::add_dependency;
+    use super::add_workspace_member;
+    use super::render_day;
+
+    #[test]
+    fn insert_sorted_module() {
+        assert_eq!(
+            add_day_module("mod day01;\nmod day03;\n", 2).unwrap(),
+            "mod day01;\nmod day02;\nmod day03;\n"
+        );
+        assert_eq!(
+            add_day_module("mod day01;\nmod day02;", 3).unwrap(),
+            "mod day01;\nmod day02;\nmod day03;"
+        );
+        assert_eq!(add_day_module("", 1).unwrap(), "mod day01;");
+        assert!(add_day_module("mod day01;\n", 1).is_none());
+    }
+
+    #[test]
+    fn render_template() {
+        let source = render_day(2025, 13);
+        assert!(source.contains("Solver::new(2025, 13, solve_all)"));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn wire_new_year() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc24\",\n    \"cli\",\n]\n\n\
+                        [workspace.dependencies]\nanyhow = \"1\"\naoc24 = { path = \"aoc24\" }\nclap = \"4\"";
+        let manifest = add_workspace_member(manifest, "aoc26").unwrap().unwrap();
+        let manifest = add_dependency(
+            &manifest,
+            "workspace.dependencies",
+            "aoc26 = { path = \"aoc26\" }",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            manifest,
+            "[workspace]\nmembers = [\n    \"aoc24\",\n    \"aoc26\",\n    \"cli\",\n]\n\n\
+             [workspace.dependencies]\nanyhow = \"1\"\naoc24 = { path = \"aoc24\" }\n\
+             aoc26 = { path = \"aoc26\" }\nclap = \"4\""
+        );
+        assert!(add_workspace_member(&manifest, "aoc26").unwrap().is_none());
+
+        let lib_rs = "pub mod report;\n\nuse crate::report::RunReport;\nuse anyhow::Context;\n\
+                      use aoc24 as _;\nuse rayon::ThreadPool;\n";
+        assert_eq!(
+            add_crate_import(lib_rs, "aoc26").unwrap().unwrap(),
+            "pub mod report;\n\nuse crate::report::RunReport;\nuse anyhow::Context;\n\
+             use aoc24 as _;\nuse aoc26 as _;\nuse rayon::ThreadPool;\n"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use human_repr::HumanDuration;
+use runner::Filter;
+use runner::RunOptions;
+use runner::report::Status;
+use serde::Serialize;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use tiny_http::Header;
+use tiny_http::Method;
+use tiny_http::Request;
+use tiny_http::Response;
+use tiny_http::Server;
+
+/// Limits applied to every request.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Largest input accepted by `POST /solve/{year}/{day}`.
+    pub max_input_bytes: usize,
+    /// Solvers still running after this long are killed.
+    pub timeout: Duration,
+}
+
+#[derive(Serialize)]
+struct SolverEntry {
+    year: u16,
+    day: u8,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+/// Serves the registered solvers as a JSON API until the process is stopped:
+///
+/// - `GET /solvers` lists the registered solvers.
+/// - `POST /solve/{year}/{day}` solves the request body and returns the run
+///   report: `200` on success, `422` if the solver failed, `504` on timeout.
+///
+/// Each solve runs in a child process, so a hanging or crashing solver only
+/// affects its own request. Requests are handled on `jobs` threads.
+pub fn serve(host: &str, port: u16, jobs: usize, limits: Limits) -> anyhow::Result<()> {
+    let server = Server::http((host, port))
+        .map_err(|e| anyhow::anyhow!("Failed to listen on {host}:{port}: {e}"))?;
+    println!("Listening on http://{}", server.server_addr());
+
+    let server = Arc::new(server);
+    let workers = (0..jobs.max(1))
+        .map(|_| {
+            let server = Arc::clone(&server);
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(request, &limits);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for worker in workers {
+        let _ = worker.join();
+    }
+    Ok(())
+}
+
+fn handle(mut request: Request, limits: &Limits) {
+    let started = Instant::now();
+    let (method, url) = (request.method().clone(), request.url().to_string());
+    let (status, body) = route(&mut request, &method, &url, limits);
+    println!(
+        "{method} {url} -> {status} ({})",
+        started.elapsed().human_duration()
+    );
+
+    let content_type =
+        Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).expect("valid header");
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type);
+    let _ = request.respond(response);
+}
+
+fn route(request: &mut Request, method: &Method, url: &str, limits: &Limits) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    match (method, segments.as_slice()) {
+        (Method::Get, ["solvers"]) => {
+            let solvers = runner::solvers(&Filter::default())
+                .iter()
+                .map(|s| SolverEntry {
+                    year: s.year,
+                    day: s.day,
+                })
+                .collect::<Vec<_>>();
+            json(200, &solvers)
+        }
+        (Method::Post, ["solve", year, day]) => solve(request, year, day, limits),
+        (_, ["solvers"] | ["solve", _, _]) => error(405, "Method not allowed".to_string()),
+        _ => error(404, format!("No route for {path}")),
+    }
+}
+
+fn solve(request: &mut Request, year: &str, day: &str, limits: &Limits) -> (u16, String) {
+    let (Ok(year), Ok(day)) = (year.parse::<u16>(), day.parse::<u8>()) else {
+        return error(400, "Year and day must be numbers".to_string());
+    };
+    let Some(solver) = runner::find_solver(year, day) else {
+        return error(404, format!("No solver for year {year} day {day}"));
+    };
+
+    let too_large = || {
+        error(
+            413,
+            format!("Input exceeds {} bytes", limits.max_input_bytes),
+        )
+    };
+    if request
+        .body_length()
+        .is_some_and(|length| length > limits.max_input_bytes)
+    {
+        return too_large();
+    }
+    let mut body = Vec::new();
+    let limit = limits.max_input_bytes as u64 + 1;
+    if let Err(err) = request.as_reader().take(limit).read_to_end(&mut body) {
+        return error(400, format!("Failed to read request body: {err}"));
+    }
+    if body.len() > limits.max_input_bytes {
+        return too_large();
+    }
+    let Ok(input) = String::from_utf8(body) else {
+        return error(400, "Input must be UTF-8".to_string());
+    };
+
+    let options = RunOptions {
+        jobs: 1,
+        timeout: Some(limits.timeout),
+    };
+    let report = runner::run_input(solver, &input, &options);
+    let status = match report.status {
+        Status::Succeeded => 200,
+        Status::Failed => 422,
+        Status::TimedOut => 504,
+        Status::Skipped => 500,
+    };
+    json(status, &report)
+}
+
+fn json(status: u16, value: &impl Serialize) -> (u16, String) {
+    match serde_json::to_string(value) {
+        Ok(body) => (status, body),
+        Err(err) => error(500, err.to_string()),
+    }
+}
+
+fn error(status: u16, message: String) -> (u16, String) {
+    let body = ErrorBody { error: message };
+    (status, serde_json::to_string(&body).unwrap_or_default())
+}
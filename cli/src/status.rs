@@ -0,0 +1,283 @@
+use crate::calendar;
+use human_repr::HumanDuration;
+use runner::answers::AnswerBook;
+use runner::answers::Verdict;
+use runner::config;
+use runner::last_runs::LastRuns;
+use runner::report::RunReport;
+use runner::report::Status;
+use std::path::Path;
+
+pub const LEGEND: &str = "✔ done/verified  ✘ wrong answer  ? recorded, not checked by a run  \
+                          · missing  enc encrypted input";
+
+/// What is known about one part's answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Missing,
+    /// Recorded, but the last run did not produce an answer to compare with.
+    Recorded,
+    /// The last run produced the recorded answer.
+    Verified,
+    /// The last run produced a different answer than the recorded one.
+    Wrong,
+}
+
+impl Answer {
+    fn new(expected: Option<&str>, last_run: Option<&RunReport>, actual: Option<&str>) -> Self {
+        match last_run.map(|r| r.status) {
+            Some(Status::Succeeded) => {
+                match Verdict::compare(expected, actual.unwrap_or_default()) {
+                    Verdict::Pass => Self::Verified,
+                    Verdict::Fail => Self::Wrong,
+                    Verdict::Unknown => Self::Missing,
+                }
+            }
+            _ if expected.is_some() => Self::Recorded,
+            _ => Self::Missing,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Missing => "·",
+            Self::Recorded => "?",
+            Self::Verified => "✔",
+            Self::Wrong => "✘",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Input {
+    Missing,
+    Plaintext,
+    Encrypted,
+}
+
+/// Everything `status` shows about a day.
+#[derive(Debug, Clone)]
+pub struct DayStatus {
+    pub day: u8,
+    pub unlocked: bool,
+    pub solver: bool,
+    pub input: Input,
+    /// Number of example inputs in the tests directory.
+    pub examples: usize,
+    pub answers: [Answer; 2],
+    /// Last run on the default input.
+    pub last_run: Option<RunReport>,
+}
+
+/// Gathers the status of every day of `year` from the registered solvers, the
+/// workspace files and the last runs.
+pub fn collect(year: u16, book: &AnswerBook, last_runs: &LastRuns) -> Vec<DayStatus> {
+    let workspace = config::workspace();
+    let examples = runner::inputs_in_dir(&workspace.tests_dir(year)).unwrap_or_default();
+    (1..=calendar::days_in_event(year))
+        .map(|day| {
+            let input = if workspace.input_path(year, day).exists() {
+                Input::Plaintext
+            } else if workspace.encrypted_input_path(year, day).exists() {
+                Input::Encrypted
+            } else {
+                Input::Missing
+            };
+            let last_run = last_runs.get(year, day);
+            let expected = book.get(day);
+            DayStatus {
+                day,
+                unlocked: calendar::is_unlocked(year, day),
+                solver: runner::find_solver(year, day).is_some(),
+                input,
+                examples: examples.iter().filter(|p| is_example_of(p, day)).count(),
+                answers: [
+                    Answer::new(
+                        expected.and_then(|a| a.part_one.as_deref()),
+                        last_run,
+                        last_run.and_then(|r| r.part_one.as_deref()),
+                    ),
+                    Answer::new(
+                        expected.and_then(|a| a.part_two.as_deref()),
+                        last_run,
+                        last_run.and_then(|r| r.part_two.as_deref()),
+                    ),
+                ],
+                last_run: last_run.cloned(),
+            }
+        })
+        .collect()
+}
+
+/// Whether `path` is an example for `day`: `DD.txt` or `DD-N.txt`.
+fn is_example_of(path: &Path, day: u8) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    name.strip_prefix(&format!("{day:02}"))
+        .is_some_and(|rest| rest == ".txt" || rest.starts_with('-'))
+}
+
+/// Renders the calendar of a year, one row per day, followed by totals.
+pub fn render(year: u16, days: &[DayStatus]) -> String {
+    let mut text = format!("Advent of Code {year}\n\n");
+    text.push_str(&format!(
+        "{:>3}  {:<8}{:<7}{:<10}{:<9}{}\n",
+        "Day", "Solver", "Input", "Examples", "Answers", "Last run"
+    ));
+
+    for status in days {
+        if !status.unlocked && !status.solver {
+            text.push_str(&format!("{:>3}  locked\n", status.day));
+            continue;
+        }
+        let check = |done: bool| if done { "✔" } else { "·" };
+        let input = match status.input {
+            Input::Missing => "·",
+            Input::Plaintext => "✔",
+            Input::Encrypted => "enc",
+        };
+        let examples = match status.examples {
+            0 => "·".to_string(),
+            count => count.to_string(),
+        };
+        let answers = format!(
+            "{} {}",
+            status.answers[0].symbol(),
+            status.answers[1].symbol()
+        );
+        let last_run = match &status.last_run {
+            None => String::new(),
+            Some(report) => match (report.status, report.timings()) {
+                (Status::Succeeded, Some(timings)) => timings.total.human_duration().to_string(),
+                (Status::TimedOut, _) => "timed out".to_string(),
+                _ => "failed".to_string(),
+            },
+        };
+        let row = format!(
+            "{:>3}  {:<8}{:<7}{:<10}{:<9}{}",
+            status.day,
+            check(status.solver),
+            input,
+            examples,
+            answers,
+            last_run
+        );
+        text.push_str(row.trim_end());
+        text.push('\n');
+    }
+
+    let solvers = days.iter().filter(|d| d.solver).count();
+    let inputs = days.iter().filter(|d| d.input != Input::Missing).count();
+    let with_examples = days.iter().filter(|d| d.examples > 0).count();
+    let verified = days
+        .iter()
+        .flat_map(|d| d.answers)
+        .filter(|a| *a == Answer::Verified)
+        .count();
+    text.push_str(&format!(
+        "\n{}/{} solvers, {} inputs, {} with examples, {}/{} parts verified\n",
+        solvers,
+        days.len(),
+        inputs,
+        with_examples,
+        verified,
+        days.len() * 2
+    ));
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+    use super::DayStatus;
+    use super::Input;
+    use super::is_example_of;
+    use super::render;
+    use runner::report::Durations;
+    use runner::report::RunReport;
+    use runner::report::Status;
+    use std::path::Path;
+
+    fn last_run(part_one: &str) -> RunReport {
+        RunReport {
+            year: 2024,
+            day: 1,
+            status: Status::Succeeded,
+            part_one: Some(part_one.to_string()),
+            part_two: Some("31".to_string()),
+            durations: Durations {
+                total_ms: Some(1.5),
+                ..Durations::default()
+            },
+            memory: None,
+            error: None,
+            input: "01.txt".to_string(),
+        }
+    }
+
+    #[test]
+    fn answer_states() {
+        let run = last_run("11");
+        assert_eq!(
+            Answer::new(Some("11"), Some(&run), Some("11")),
+            Answer::Verified
+        );
+        assert_eq!(
+            Answer::new(Some("12"), Some(&run), Some("11")),
+            Answer::Wrong
+        );
+        assert_eq!(Answer::new(Some("11"), None, None), Answer::Recorded);
+        assert_eq!(Answer::new(None, Some(&run), Some("11")), Answer::Missing);
+    }
+
+    #[test]
+    fn example_files() {
+        assert!(is_example_of(Path::new("tests/01.txt"), 1));
+        assert!(is_example_of(Path::new("tests/01-2.txt"), 1));
+        assert!(!is_example_of(Path::new("tests/011.txt"), 1));
+        assert!(!is_example_of(Path::new("tests/02.txt"), 1));
+    }
+
+    #[test]
+    fn render_calendar() {
+        let days = [
+            DayStatus {
+                day: 1,
+                unlocked: true,
+                solver: true,
+                input: Input::Plaintext,
+                examples: 2,
+                answers: [Answer::Verified, Answer::Wrong],
+                last_run: Some(last_run("11")),
+            },
+            DayStatus {
+                day: 2,
+                unlocked: true,
+                solver: false,
+                input: Input::Encrypted,
+                examples: 0,
+                answers: [Answer::Recorded, Answer::Missing],
+                last_run: None,
+            },
+            DayStatus {
+                day: 3,
+                unlocked: false,
+                solver: false,
+                input: Input::Missing,
+                examples: 0,
+                answers: [Answer::Missing, Answer::Missing],
+                last_run: None,
+            },
+        ];
+        let text = render(2024, &days);
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "Advent of Code 2024");
+        assert_eq!(lines[2], "Day  Solver  Input  Examples  Answers  Last run");
+        assert_eq!(lines[3], "  1  ✔       ✔      2         ✔ ✘      1.5ms");
+        assert_eq!(lines[4], "  2  ·       enc    ·         ? ·");
+        assert_eq!(lines[5], "  3  locked");
+        assert_eq!(
+            lines[7],
+            "1/3 solvers, 2 inputs, 1 with examples, 1/6 parts verified"
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use crate::html;
+use anyhow::Context;
+use runner::config;
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+/// How the website responded to a submitted answer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited { wait_seconds: u64 },
+    AlreadySolved,
+    Unknown { message: String },
+}
+
+impl Outcome {
+    /// Parses the HTML page returned after posting an answer.
+    pub fn from_response(html: &str) -> Self {
+        let message = article_text(html);
+
+        if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if message.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait_seconds: parse_wait_seconds(&message).unwrap_or(60),
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown { message }
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Outcome::Correct => "That's the right answer!".into(),
+            Outcome::Wrong => "That's not the right answer.".into(),
+            Outcome::TooHigh => "That's not the right answer: too high.".into(),
+            Outcome::TooLow => "That's not the right answer: too low.".into(),
+            Outcome::RateLimited { wait_seconds } => {
+                format!("Answered too recently, wait {wait_seconds}s before trying again.")
+            }
+            Outcome::AlreadySolved => "This part is already solved or not unlocked yet.".into(),
+            Outcome::Unknown { message } => format!("Unrecognized response: {message}"),
+        }
+    }
+}
+
+/// Extracts the text of the `<article>` element, which holds the verdict.
+fn article_text(page: &str) -> String {
+    let nodes = html::parse(page);
+    let text = match html::find_all(&nodes, &|e| e.name == "article").first() {
+        Some(article) => article.text(),
+        None => html::text_content(&nodes),
+    };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the "you have 1m 30s left to wait" part of a rate-limit message.
+fn parse_wait_seconds(message: &str) -> Option<u64> {
+    let start = message.find("you have ")? + "you have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+    message[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Local log of every submitted answer for a year, stored as
+/// `aocYY/resources/submissions.toml`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct History {
+    #[serde(default, rename = "guess")]
+    guesses: Vec<Guess>,
+}
+
+impl History {
+    pub fn path_for_year(year: u16) -> PathBuf {
+        config::workspace().submissions_path(year)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read submission history {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse submission history {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Failed to write submission history {}", path.display()))
+    }
+
+    pub fn push(&mut self, day: u8, part: u8, answer: &str, outcome: Outcome) {
+        self.guesses.push(Guess {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: now(),
+        });
+    }
+
+    /// Checks `answer` against previous guesses, returning why it should not
+    /// be submitted, if anything.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        self.check_at(day, part, answer, now())
+    }
+
+    fn check_at(&self, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|g| g.day == day && g.part == part);
+
+        let mut lower_bound: Option<i128> = None;
+        let mut upper_bound: Option<i128> = None;
+        let numeric_answer = answer.parse::<i128>().ok();
+
+        for guess in guesses {
+            match &guess.outcome {
+                Outcome::Correct => {
+                    return Some(format!("Already solved with answer {}.", guess.answer));
+                }
+                Outcome::RateLimited { wait_seconds } => {
+                    let ready_at = guess.timestamp + wait_seconds;
+                    if ready_at > now {
+                        return Some(format!(
+                            "Rate limited, wait another {}s before submitting.",
+                            ready_at - now
+                        ));
+                    }
+                }
+                Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow if guess.answer == answer => {
+                    return Some(format!("{answer} was already rejected."));
+                }
+                Outcome::TooHigh => {
+                    if let Ok(value) = guess.answer.parse::<i128>() {
+                        upper_bound = Some(upper_bound.map_or(value, |b| b.min(value)));
+                    }
+                }
+                Outcome::TooLow => {
+                    if let Ok(value) = guess.answer.parse::<i128>() {
+                        lower_bound = Some(lower_bound.map_or(value, |b| b.max(value)));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(value) = numeric_answer {
+            if let Some(upper) = upper_bound.filter(|upper| value >= *upper) {
+                return Some(format!(
+                    "{answer} is too high, answers must be below {upper}."
+                ));
+            }
+            if let Some(lower) = lower_bound.filter(|lower| value <= *lower) {
+                return Some(format!(
+                    "{answer} is too low, answers must be above {lower}."
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::History;
+    use super::Outcome;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn parse_outcomes() {
+        assert_eq!(
+            Outcome::from_response(&page(
+                "That's the right answer! You are <span>one gold star</span> closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::from_response(&page("That's not the right answer.")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "You gave an answer too recently; you have 1m 5s left to wait."
+            )),
+            Outcome::RateLimited { wait_seconds: 65 }
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn refuse_known_guesses() {
+        let mut history = History::default();
+        history.push(1, 1, "100", Outcome::TooHigh);
+        history.push(1, 1, "10", Outcome::TooLow);
+        history.push(1, 1, "50", Outcome::Wrong);
+
+        assert!(history.check(1, 1, "50").is_some());
+        assert!(history.check(1, 1, "100").is_some());
+        assert!(history.check(1, 1, "150").is_some());
+        assert!(history.check(1, 1, "5").is_some());
+        assert!(history.check(1, 1, "42").is_none());
+        assert!(history.check(1, 2, "150").is_none());
+
+        history.push(1, 1, "42", Outcome::Correct);
+        assert!(history.check(1, 1, "43").is_some());
+
+        let serialized = toml::to_string(&history).unwrap();
+        let reloaded: History = toml::from_str(&serialized).unwrap();
+        assert_eq!(reloaded.guesses, history.guesses);
+    }
+
+    #[test]
+    fn refuse_while_rate_limited() {
+        let mut history = History::default();
+        history.push(2, 1, "7", Outcome::RateLimited { wait_seconds: 30 });
+        let submitted_at = history.guesses[0].timestamp;
+
+        assert!(history.check_at(2, 1, "8", submitted_at + 10).is_some());
+        assert!(history.check_at(2, 1, "8", submitted_at + 30).is_none());
+    }
+}
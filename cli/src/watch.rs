@@ -0,0 +1,361 @@
+use crate::scaffold;
+use anyhow::Context;
+use colored::Colorize;
+use human_repr::HumanDuration;
+use runner::config;
+use runner::isolation;
+use runner::report::RunReport;
+use runner::report::Status;
+use std::collections::BTreeMap;
+use std::io::BufReader;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::SystemTime;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Rebuild the CLI with cargo before each run. Without it, the solver
+    /// compiled into the running binary is used.
+    pub build: bool,
+    /// Run the day's unit tests after building.
+    pub tests: bool,
+}
+
+/// Modification time and size of every watched file.
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Runs the solver for a day, then again whenever its source file, the tests
+/// directory or its input changes, until the process is stopped. Each run is
+/// compared with the last successful one.
+pub fn watch(year: u16, day: u8, options: &Options) -> anyhow::Result<()> {
+    let workspace = config::workspace();
+    let paths = [
+        scaffold::day_source_path(year, day),
+        workspace.tests_dir(year),
+        workspace.input_path(year, day),
+        workspace.encrypted_input_path(year, day),
+    ];
+    println!("Watching:");
+    for path in &paths {
+        println!("  {}", path.display());
+    }
+
+    let mut files = snapshot(&paths);
+    let mut previous: Option<RunReport> = None;
+    loop {
+        if let Some(report) = run_cycle(year, day, options, previous.as_ref())?
+            && report.status == Status::Succeeded
+        {
+            previous = Some(report);
+        }
+
+        let changed = loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let changed = changed_files(&files, &snapshot(&paths));
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        // Editors often write a file in several steps, so let it settle.
+        std::thread::sleep(POLL_INTERVAL);
+        files = snapshot(&paths);
+
+        println!();
+        for path in changed {
+            println!("{} {}", "Changed".bold().blue(), path.display());
+        }
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        if path.is_dir() {
+            let entries = std::fs::read_dir(path).into_iter().flatten().flatten();
+            for entry in entries {
+                record(&mut snapshot, &entry.path());
+            }
+        } else {
+            record(&mut snapshot, path);
+        }
+    }
+    snapshot
+}
+
+fn record(snapshot: &mut Snapshot, path: &Path) {
+    if let Ok(metadata) = std::fs::metadata(path)
+        && metadata.is_file()
+    {
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        snapshot.insert(path.to_path_buf(), (modified, metadata.len()));
+    }
+}
+
+/// Files added, removed or modified between two snapshots.
+fn changed_files(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let removed = before.keys().filter(|path| !after.contains_key(*path));
+    let added_or_modified = after
+        .iter()
+        .filter(|(path, state)| before.get(*path) != Some(state))
+        .map(|(path, _)| path);
+    let mut changed = removed
+        .chain(added_or_modified)
+        .cloned()
+        .collect::<Vec<_>>();
+    changed.sort();
+    changed
+}
+
+/// Builds, tests and runs the solver. Returns `None` if it could not be run.
+fn run_cycle(
+    year: u16,
+    day: u8,
+    options: &Options,
+    previous: Option<&RunReport>,
+) -> anyhow::Result<Option<RunReport>> {
+    if options.build {
+        let status = cargo()
+            .args(["build", "--release", "--quiet", "--bin", "cli"])
+            .status()
+            .context("Failed to run cargo")?;
+        if !status.success() {
+            println!("{} Build failed", "✘".red());
+            return Ok(None);
+        }
+        if options.tests {
+            run_tests(year, day)?;
+        }
+    }
+
+    let Some(report) = run_solver(year, day, options.build)? else {
+        return Ok(None);
+    };
+    match report.status {
+        Status::Succeeded => {
+            for line in compare(previous, &report) {
+                print!("{}: {}", line.label.bold().green(), line.value);
+                match line.change {
+                    Some(change) => println!(" {}", format!("({change})").yellow()),
+                    None => println!(),
+                }
+            }
+        }
+        Status::Skipped => println!("{} No input at {}", "✘".red(), report.input),
+        Status::Failed | Status::TimedOut => println!(
+            "{} {}",
+            "✘".red(),
+            report.error.as_deref().unwrap_or("The solver failed")
+        ),
+    }
+    Ok(Some(report))
+}
+
+fn cargo() -> Command {
+    let mut command = Command::new("cargo");
+    command.current_dir(&config::workspace().root);
+    command
+}
+
+fn run_tests(year: u16, day: u8) -> anyhow::Result<()> {
+    let filter = format!("{}::", scaffold::day_module(day));
+    let output = cargo()
+        .args(["test", "--release", "--quiet", "--lib"])
+        .args(["-p", &scaffold::crate_name(year), &filter])
+        .output()
+        .context("Failed to run cargo")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if output.status.success() {
+        let counts = test_counts(&stdout).unwrap_or("no tests");
+        println!("{} Tests: {counts}", "✔".green());
+    } else {
+        print!("{stdout}");
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        println!("{} Tests failed", "✘".red());
+    }
+    Ok(())
+}
+
+/// Pass and fail counts from libtest output, e.g. "2 passed; 0 failed".
+fn test_counts(stdout: &str) -> Option<&str> {
+    let summary = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("test result: "))?;
+    let counts = summary
+        .split_once(". ")
+        .map_or(summary, |(_, counts)| counts);
+    let end = counts
+        .match_indices(';')
+        .nth(1)
+        .map_or(counts.len(), |(i, _)| i);
+    Some(&counts[..end])
+}
+
+/// Runs the solver in a child process, through `cargo run` when `build` is set
+/// so that the fresh build is used.
+fn run_solver(year: u16, day: u8, build: bool) -> anyhow::Result<Option<RunReport>> {
+    let mut command = if build {
+        let mut command = cargo();
+        command.args(["run", "--release", "--quiet", "--bin", "cli", "--"]);
+        command
+    } else {
+        Command::new(std::env::current_exe()?)
+    };
+    let mut child = command
+        .args(["run-one", "--year", &year.to_string()])
+        .args(["--day", &day.to_string()])
+        .arg("--input")
+        .arg(runner::day_input_path(year, day))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("Failed to run the solver")?;
+    let stdout = child
+        .stdout
+        .take()
+        .context("Solver process has no stdout")?;
+    let report = isolation::read_report(BufReader::new(stdout));
+    let status = child.wait()?;
+    if !status.success() {
+        println!("{} The solver did not run ({status})", "✘".red());
+        return Ok(None);
+    }
+    Ok(Some(report?))
+}
+
+/// A line of [`compare`].
+#[derive(Debug, PartialEq)]
+struct Line {
+    label: &'static str,
+    value: String,
+    /// How the value changed since the previous run.
+    change: Option<String>,
+}
+
+/// Answers and total time of `report`, with what changed since `previous`.
+fn compare(previous: Option<&RunReport>, report: &RunReport) -> Vec<Line> {
+    let answers = [
+        ("Part one", &report.part_one, previous.map(|p| &p.part_one)),
+        ("Part two", &report.part_two, previous.map(|p| &p.part_two)),
+    ];
+    let mut lines = answers
+        .into_iter()
+        .map(|(label, answer, previous)| Line {
+            label,
+            value: answer.clone().unwrap_or_default(),
+            change: previous
+                .filter(|previous| *previous != answer)
+                .map(|previous| format!("was {}", previous.as_deref().unwrap_or_default())),
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(total_ms) = report.durations.total_ms {
+        let previous_ms = previous.and_then(|p| p.durations.total_ms);
+        lines.push(Line {
+            label: "Time",
+            value: format_ms(total_ms),
+            change: previous_ms.filter(|ms| *ms > 0.0).map(|previous_ms| {
+                let percent = (total_ms - previous_ms) / previous_ms * 100.0;
+                format!("{percent:+.0}% from {}", format_ms(previous_ms))
+            }),
+        });
+    }
+    lines
+}
+
+fn format_ms(ms: f64) -> String {
+    Duration::from_secs_f64(ms / 1000.0)
+        .human_duration()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Line;
+    use super::changed_files;
+    use super::compare;
+    use super::snapshot;
+    use super::test_counts;
+    use runner::report::Durations;
+    use runner::report::RunReport;
+    use runner::report::Status;
+
+    fn report(part_one: &str, part_two: &str, total_ms: f64) -> RunReport {
+        RunReport {
+            year: 2024,
+            day: 1,
+            status: Status::Succeeded,
+            part_one: Some(part_one.to_string()),
+            part_two: Some(part_two.to_string()),
+            durations: Durations {
+                total_ms: Some(total_ms),
+                ..Durations::default()
+            },
+            memory: None,
+            error: None,
+            input: "01.txt".to_string(),
+        }
+    }
+
+    #[test]
+    fn compare_with_previous_run() {
+        let first = report("11", "31", 2.0);
+        let lines = compare(None, &first);
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.change.is_none()));
+
+        let second = report("11", "32", 1.0);
+        let lines = compare(Some(&first), &second);
+        assert_eq!(lines[0].change, None);
+        assert_eq!(
+            lines[1],
+            Line {
+                label: "Part two",
+                value: "32".to_string(),
+                change: Some("was 31".to_string()),
+            }
+        );
+        assert!(
+            lines[2]
+                .change
+                .as_deref()
+                .unwrap()
+                .starts_with("-50% from ")
+        );
+    }
+
+    #[test]
+    fn libtest_counts() {
+        let stdout = "\nrunning 2 tests\n..\ntest result: ok. 2 passed; 0 failed; 0 ignored; \
+                      0 measured; 3 filtered out; finished in 0.00s\n\n";
+        assert_eq!(test_counts(stdout), Some("2 passed; 0 failed"));
+        assert_eq!(test_counts(""), None);
+    }
+
+    #[test]
+    fn detect_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = [dir.path().join("tests"), dir.path().join("01.txt")];
+        std::fs::create_dir(&paths[0]).unwrap();
+        std::fs::write(&paths[1], "1").unwrap();
+        let before = snapshot(&paths);
+        assert!(changed_files(&before, &snapshot(&paths)).is_empty());
+
+        std::fs::write(&paths[1], "12").unwrap();
+        std::fs::write(paths[0].join("01-1.txt"), "").unwrap();
+        let after = snapshot(&paths);
+        assert_eq!(
+            changed_files(&before, &after),
+            [paths[1].clone(), paths[0].join("01-1.txt")]
+        );
+
+        std::fs::remove_file(&paths[1]).unwrap();
+        assert_eq!(changed_files(&after, &snapshot(&paths)), [paths[1].clone()]);
+    }
+}
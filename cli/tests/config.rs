@@ -0,0 +1,86 @@
+mod common;
+
+use common::MockServer;
+use common::run_cli;
+
+/// Creates a workspace whose `aoc.toml` moves inputs and the session token,
+/// and returns it with a nested directory to run the CLI from.
+fn configured_workspace() -> (tempfile::TempDir, std::path::PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("aoc.toml"),
+        "input_dir = \"inputs/{year}\"\ndefault_year = 2024\nsession_file = \"session.txt\"\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("session.txt"), "file-session\n").unwrap();
+    let nested = dir.path().join("aoc24/src");
+    std::fs::create_dir_all(&nested).unwrap();
+    (dir, nested)
+}
+
+#[test]
+fn resolves_inputs_from_nested_directory() {
+    let (dir, nested) = configured_workspace();
+    let inputs = dir.path().join("inputs/2024");
+    std::fs::create_dir_all(&inputs).unwrap();
+    std::fs::write(
+        inputs.join("01.txt"),
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    )
+    .unwrap();
+
+    let output = run_cli(
+        &nested,
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1", "-f", "csv"],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let row = stdout.lines().nth(1).unwrap();
+    assert!(row.starts_with("2024,1,succeeded,11,31,"), "{row}");
+    assert!(row.ends_with("inputs/2024/01.txt"), "{row}");
+}
+
+#[test]
+fn downloads_with_configured_defaults() {
+    let (dir, nested) = configured_workspace();
+    let server = MockServer::start(|_| (200, "1 2 3\n".into()));
+
+    let output = run_cli(&nested, &server.base_url, &["get-input", "--day", "3"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let written = std::fs::read_to_string(dir.path().join("inputs/2024/03.txt")).unwrap();
+    assert_eq!(written, "1 2 3\n");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/2024/day/3/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=file-session"));
+}
+
+#[test]
+fn reports_invalid_config() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("aoc.toml"), "input_dirs = \"inputs\"\n").unwrap();
+
+    let output = run_cli(dir.path(), "http://127.0.0.1:1", &["list"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to parse"), "{stderr}");
+}
+
+#[test]
+fn requires_year_without_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["get-input", "--day", "3"],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("default_year"));
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::run_cli;
+
+const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<pre><code>xmul(2,4)%&amp;mul[3,7]   
+</code></pre>
+<p>Adding up the results produces <code><em>161</em></code>.</p>
+</article>
+<article class="day-desc"><h2>--- Part Two ---</h2>
+<pre><code>xmul(2,4)&amp;don't()_mul(5,5)
+</code></pre>
+<p>This time, the sum is <code><em>48</em></code>.</p>
+</article></main></body></html>"#;
+
+#[test]
+fn lists_and_saves_examples() {
+    let dir = tempfile::tempdir().unwrap();
+    let puzzles = dir.path().join("aoc24/resources/puzzles");
+    std::fs::create_dir_all(&puzzles).unwrap();
+    std::fs::write(puzzles.join("03.html"), PAGE).unwrap();
+    let get_examples = |args: &[&str]| {
+        let mut full = vec!["get-examples", "-y", "2024", "-d", "3"];
+        full.extend_from_slice(args);
+        run_cli(dir.path(), "http://127.0.0.1:1", &full)
+    };
+
+    let listing = get_examples(&[]);
+    assert!(listing.status.success());
+    let stdout = String::from_utf8_lossy(&listing.stdout);
+    assert!(stdout.contains("Found 2 example block(s)"));
+    assert!(stdout.contains("Expected answer for part 2: 48"));
+
+    assert!(
+        get_examples(&["--save", "0", "--part", "1", "--answer"])
+            .status
+            .success()
+    );
+    assert!(
+        get_examples(&["--save", "1", "--part", "2"])
+            .status
+            .success()
+    );
+
+    let tests = dir.path().join("aoc24/resources/tests");
+    assert_eq!(
+        std::fs::read_to_string(tests.join("03-1.txt")).unwrap(),
+        "xmul(2,4)%&mul[3,7]   \n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(tests.join("03-1.expected.txt")).unwrap(),
+        "161\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(tests.join("03-2.txt")).unwrap(),
+        "xmul(2,4)&don't()_mul(5,5)\n"
+    );
+
+    let clobber = get_examples(&["--save", "1", "--part", "1"]);
+    assert!(!clobber.status.success());
+    assert!(String::from_utf8_lossy(&clobber.stderr).contains("already exists"));
+    assert!(
+        get_examples(&["--save", "1", "--part", "1", "--force"])
+            .status
+            .success()
+    );
+}
@@ -0,0 +1,199 @@
+mod common;
+
+use common::MockServer;
+use common::run_cli;
+
+fn get_input(server: &MockServer, dir: &std::path::Path) -> std::process::Output {
+    run_cli(
+        dir,
+        &server.base_url,
+        &["get-input", "--year", "2024", "--day", "3"],
+    )
+}
+
+fn stderr(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn downloads_input() {
+    let server = MockServer::start(|_| (200, "1 2 3\n".into()));
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = get_input(&server, dir.path());
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let written = std::fs::read_to_string(dir.path().join("aoc24/resources/input/03.txt")).unwrap();
+    assert_eq!(written, "1 2 3\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2024/day/3/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    assert!(
+        requests[0]
+            .header("user-agent")
+            .is_some_and(|agent| agent.starts_with("aoc-cli/"))
+    );
+}
+
+#[test]
+fn skips_stored_input_unless_forced() {
+    let server = MockServer::start(|_| (200, "1 2 3\n".into()));
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("aoc.toml"), "request_interval_ms = 0\n").unwrap();
+
+    assert!(get_input(&server, dir.path()).status.success());
+    let output = get_input(&server, dir.path());
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already stored"));
+    assert_eq!(server.requests().len(), 1);
+
+    let output = run_cli(
+        dir.path(),
+        &server.base_url,
+        &["get-input", "--year", "2024", "--day", "3", "--force"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn downloads_missing_days_with_throttle() {
+    let server = MockServer::start(|request| (200, format!("{}\n", request.path)));
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("aoc.toml"),
+        "user_agent = \"me@example.com\"\nrequest_interval_ms = 100\n",
+    )
+    .unwrap();
+    let input_dir = dir.path().join("aoc25/resources/input");
+    std::fs::create_dir_all(&input_dir).unwrap();
+    std::fs::write(input_dir.join("02.txt"), "stored\n").unwrap();
+
+    let started = std::time::Instant::now();
+    let output = run_cli(
+        dir.path(),
+        &server.base_url,
+        &["get-input", "--year", "2025"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(started.elapsed() >= std::time::Duration::from_millis(900));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 11);
+    assert!(requests.iter().all(|r| r.path != "/2025/day/2/input"));
+    assert!(
+        requests
+            .iter()
+            .all(|r| r.header("user-agent") == Some("me@example.com"))
+    );
+    assert_eq!(
+        std::fs::read_to_string(input_dir.join("12.txt")).unwrap(),
+        "/2025/day/12/input\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(input_dir.join("02.txt")).unwrap(),
+        "stored\n"
+    );
+    assert!(dir.path().join(".aoc/last-request").exists());
+}
+
+#[test]
+fn reports_locked_puzzle() {
+    for status in [400, 404] {
+        let server = MockServer::start(move |_| {
+            (
+                status,
+                "Please don't repeatedly request this endpoint before it unlocks!".into(),
+            )
+        });
+        let dir = tempfile::tempdir().unwrap();
+
+        let output = get_input(&server, dir.path());
+        assert!(!output.status.success());
+        assert!(
+            stderr(&output).contains("not unlocked yet"),
+            "{}",
+            stderr(&output)
+        );
+        assert!(!dir.path().join("aoc24/resources/input/03.txt").exists());
+    }
+}
+
+#[test]
+fn reports_expired_session() {
+    let server = MockServer::start(|_| {
+        (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".into(),
+        )
+    });
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = get_input(&server, dir.path());
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("invalid or has expired"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn reports_server_error() {
+    let server = MockServer::start(|_| (500, "Internal Server Error".into()));
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = get_input(&server, dir.path());
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("internal error"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(stderr(&output).contains("HTTP 500"), "{}", stderr(&output));
+}
+
+#[test]
+fn wait_retries_while_locked() {
+    let attempts = std::sync::atomic::AtomicUsize::new(0);
+    let server = MockServer::start(move |_| {
+        if attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!".into(),
+            )
+        } else {
+            (200, "1 2 3\n".into())
+        }
+    });
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("aoc.toml"), "request_interval_ms = 0\n").unwrap();
+
+    let output = run_cli(
+        dir.path(),
+        &server.base_url,
+        &["get-input", "--year", "2024", "--day", "3", "--wait"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Not unlocked yet, retrying"));
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("aoc24/resources/input/03.txt")).unwrap(),
+        "1 2 3\n"
+    );
+}
+
+#[test]
+fn wait_needs_a_day() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["get-input", "--year", "2024", "--wait"],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("--day"), "{}", stderr(&output));
+}
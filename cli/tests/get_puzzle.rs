@@ -0,0 +1,59 @@
+mod common;
+
+use common::MockServer;
+use common::run_cli;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+const PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>Find the <em>total distance</em>.</p>
+<pre><code>3   4
+4   3
+</code></pre></article>"#;
+
+const PART_TWO: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Find the similarity score: <code><em>31</em></code>.</p></article>"#;
+
+#[test]
+fn downloads_part_two_once_unlocked() {
+    let part_two_unlocked = Arc::new(AtomicBool::new(false));
+    let unlocked = Arc::clone(&part_two_unlocked);
+    let server = MockServer::start(move |_| {
+        let mut page = format!("<html><body><main>{PART_ONE}");
+        if unlocked.load(Ordering::SeqCst) {
+            page.push_str(PART_TWO);
+        }
+        page.push_str("</main></body></html>");
+        (200, page)
+    });
+    let dir = tempfile::tempdir().unwrap();
+    let get_puzzle = || {
+        run_cli(
+            dir.path(),
+            &server.base_url,
+            &["get-puzzle", "-y", "2024", "-d", "1"],
+        )
+    };
+    let markdown_path = dir.path().join("aoc24/resources/puzzles/01.md");
+
+    let output = get_puzzle();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Part two is not unlocked yet"));
+    let markdown = std::fs::read_to_string(&markdown_path).unwrap();
+    assert_eq!(
+        markdown,
+        "## Day 1: Historian Hysteria\n\nFind the *total distance*.\n\n```\n3   4\n4   3\n```\n"
+    );
+    assert!(dir.path().join("aoc24/resources/puzzles/01.html").exists());
+
+    part_two_unlocked.store(true, Ordering::SeqCst);
+    assert!(get_puzzle().status.success());
+    let markdown = std::fs::read_to_string(&markdown_path).unwrap();
+    assert!(markdown.ends_with("## Part Two\n\nFind the similarity score: **`31`**.\n"));
+
+    // Both parts are stored, so nothing is downloaded again.
+    assert!(get_puzzle().status.success());
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(server.requests()[0].path, "/2024/day/1");
+}
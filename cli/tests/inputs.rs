@@ -0,0 +1,161 @@
+mod common;
+
+use common::MockServer;
+use common::run_cli;
+use std::path::Path;
+use std::process::Output;
+
+const DAY_ONE_INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+fn run_with_key(dir: &Path, base_url: &str, args: &[&str]) -> Output {
+    std::fs::write(dir.join(".env"), "AOC_INPUT_KEY=hunter2\n").unwrap();
+    let output = run_cli(dir, base_url, args);
+    std::fs::remove_file(dir.join(".env")).unwrap();
+    output
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn encrypted_inputs_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let plaintext = dir.path().join("aoc24/resources/input/01.txt");
+    let encrypted = dir
+        .path()
+        .join("aoc24/resources/input-encrypted/01.txt.enc");
+    std::fs::create_dir_all(plaintext.parent().unwrap()).unwrap();
+    std::fs::write(&plaintext, DAY_ONE_INPUT).unwrap();
+
+    let output = run_with_key(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["inputs", "encrypt", "-y", "2024", "--remove"],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert!(!plaintext.exists());
+    let blob = std::fs::read(&encrypted).unwrap();
+    assert!(!String::from_utf8_lossy(&blob).contains("3   4"));
+
+    // `run` decrypts in memory and leaves no plaintext behind.
+    let output = run_with_key(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1", "-f", "csv"],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert!(
+        stdout(&output)
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("2024,1,succeeded,11,31,")
+    );
+    assert!(!plaintext.exists());
+
+    let output = run_with_key(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["inputs", "status", "-y", "2024"],
+    );
+    assert!(
+        stdout(&output).contains("Day 01: encrypted only"),
+        "{output:?}"
+    );
+
+    let output = run_with_key(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["inputs", "decrypt", "-y", "2024"],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(std::fs::read_to_string(&plaintext).unwrap(), DAY_ONE_INPUT);
+
+    // Without the passphrase the encrypted input cannot be read.
+    std::fs::remove_file(&plaintext).unwrap();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1", "-f", "json"],
+    );
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["solvers"][0]["status"], "failed");
+    assert!(
+        report["solvers"][0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("AOC_INPUT_KEY")
+    );
+}
+
+#[test]
+fn decrypt_keeps_differing_plaintext() {
+    let dir = tempfile::tempdir().unwrap();
+    let plaintext = dir.path().join("aoc24/resources/input/01.txt");
+    std::fs::create_dir_all(plaintext.parent().unwrap()).unwrap();
+    std::fs::write(&plaintext, DAY_ONE_INPUT).unwrap();
+    let output = run_with_key(dir.path(), "http://127.0.0.1:1", &["inputs", "encrypt"]);
+    assert!(output.status.success(), "{output:?}");
+
+    std::fs::write(&plaintext, "1   1\n").unwrap();
+    let output = run_with_key(dir.path(), "http://127.0.0.1:1", &["inputs", "status"]);
+    assert!(stdout(&output).contains("Day 01: differs"), "{output:?}");
+
+    let output = run_with_key(dir.path(), "http://127.0.0.1:1", &["inputs", "decrypt"]);
+    assert!(!output.status.success());
+    assert_eq!(std::fs::read_to_string(&plaintext).unwrap(), "1   1\n");
+
+    let output = run_with_key(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["inputs", "decrypt", "--force"],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(std::fs::read_to_string(&plaintext).unwrap(), DAY_ONE_INPUT);
+}
+
+#[test]
+fn get_input_stores_encrypted_with_key_file() {
+    let server = MockServer::start(|_| (200, DAY_ONE_INPUT.to_string()));
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("key"), "hunter2\n").unwrap();
+    std::fs::write(dir.path().join("aoc.toml"), "input_key_file = \"key\"\n").unwrap();
+
+    let output = run_cli(
+        dir.path(),
+        &server.base_url,
+        &["get-input", "-y", "2024", "-d", "1"],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert!(!dir.path().join("aoc24/resources/input/01.txt").exists());
+    assert!(
+        dir.path()
+            .join("aoc24/resources/input-encrypted/01.txt.enc")
+            .exists()
+    );
+
+    let output = run_cli(
+        dir.path(),
+        &server.base_url,
+        &[
+            "run",
+            "-y",
+            "2024",
+            "-d",
+            "1",
+            "--timeout",
+            "30",
+            "-f",
+            "csv",
+        ],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert!(
+        stdout(&output)
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("2024,1,succeeded,11,31,")
+    );
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use common::MockServer;
+use common::run_cli;
+
+const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 2: Example ---</h2>
+<pre><code>7 6 4 2 1
+1 2 7 8 9
+</code></pre>
+<p>So, <code><em>2</em></code> reports are safe.</p>
+</article></main></body></html>"#;
+
+fn year_crate(dir: &std::path::Path) -> std::path::PathBuf {
+    let src = dir.join("aoc24/src");
+    std::fs::create_dir_all(&src).unwrap();
+    std::fs::write(src.join("lib.rs"), "mod day01;\nmod day03;\n").unwrap();
+    dir.join("aoc24")
+}
+
+#[test]
+fn scaffolds_day() {
+    let dir = tempfile::tempdir().unwrap();
+    let crate_dir = year_crate(dir.path());
+
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["new", "-y", "2024", "-d", "2"],
+    );
+    assert!(output.status.success());
+
+    let source = std::fs::read_to_string(crate_dir.join("src/day02.rs")).unwrap();
+    assert!(source.contains("Solver::new(2024, 2, solve_all)"));
+    assert!(source.contains("load_test_input!()"));
+    assert_eq!(
+        std::fs::read_to_string(crate_dir.join("src/lib.rs")).unwrap(),
+        "mod day01;\nmod day02;\nmod day03;\n"
+    );
+    assert!(crate_dir.join("resources/tests/02.txt").exists());
+
+    let again = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["new", "-y", "2024", "-d", "2"],
+    );
+    assert!(!again.status.success());
+    assert!(String::from_utf8_lossy(&again.stderr).contains("already exists"));
+    assert_eq!(
+        std::fs::read_to_string(crate_dir.join("src/day02.rs")).unwrap(),
+        source
+    );
+}
+
+#[test]
+fn scaffolds_day_with_downloads() {
+    let server = MockServer::start(|request| {
+        if request.path.ends_with("/input") {
+            (200, "1 2 3\n".into())
+        } else {
+            (200, PAGE.into())
+        }
+    });
+    let dir = tempfile::tempdir().unwrap();
+    let crate_dir = year_crate(dir.path());
+
+    let output = run_cli(
+        dir.path(),
+        &server.base_url,
+        &["new", "-y", "2024", "-d", "2", "--get-input", "--examples"],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(crate_dir.join("resources/input/02.txt")).unwrap(),
+        "1 2 3\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(crate_dir.join("resources/tests/02.txt")).unwrap(),
+        "7 6 4 2 1\n1 2 7 8 9\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(crate_dir.join("resources/tests/02-1.expected.txt")).unwrap(),
+        "2\n"
+    );
+    assert!(crate_dir.join("resources/puzzles/02.md").exists());
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::run_cli;
+
+#[test]
+fn bootstraps_year_crate() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("runner/src")).unwrap();
+    std::fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"aoc24\",\n    \"cli\",\n]\n\n[workspace.dependencies]\naoc24 = { path = \"aoc24\" }\nclap = \"4\"",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("runner/Cargo.toml"),
+        "[package]\nname = \"runner\"\n\n[dependencies]\naoc24 = { workspace = true }\nrayon = { workspace = true }",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("runner/src/lib.rs"),
+        "pub mod report;\n\nuse crate::report::RunReport;\nuse aoc24 as _;\nuse rayon::ThreadPool;\n",
+    )
+    .unwrap();
+
+    let output = run_cli(root, "http://127.0.0.1:1", &["new-year", "-y", "2023"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let manifest = std::fs::read_to_string(root.join("aoc23/Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"aoc23\""));
+    assert_eq!(
+        std::fs::read_to_string(root.join("aoc23/src/lib.rs")).unwrap(),
+        ""
+    );
+    assert!(root.join("aoc23/resources/tests").is_dir());
+    assert_eq!(
+        std::fs::read_to_string(root.join("aoc23/resources/.gitignore")).unwrap(),
+        "input/"
+    );
+
+    let workspace = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(workspace.contains("members = [\n    \"aoc23\",\n    \"aoc24\","));
+    assert!(workspace.contains("aoc23 = { path = \"aoc23\" }\naoc24"));
+    let runner_manifest = std::fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+    assert!(runner_manifest.contains("aoc23 = { workspace = true }\naoc24"));
+    let lib_rs = std::fs::read_to_string(root.join("runner/src/lib.rs")).unwrap();
+    assert!(lib_rs.contains("use aoc23 as _;\nuse aoc24 as _;"));
+
+    let again = run_cli(root, "http://127.0.0.1:1", &["new-year", "-y", "2023"]);
+    assert!(!again.status.success());
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::run_cli_with_stdin;
+
+const DAY_NINE_EXAMPLE: &str = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n";
+
+/// Creates a workspace with an input and an example for 2025 day 9, which is
+/// a phased solver. The input is a square, so its answers differ from the
+/// example's.
+fn workspace() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let resources = dir.path().join("aoc25/resources");
+    std::fs::create_dir_all(resources.join("input")).unwrap();
+    std::fs::create_dir_all(resources.join("tests")).unwrap();
+    std::fs::write(resources.join("input/09.txt"), "1,1\n3,1\n3,3\n1,3\n").unwrap();
+    std::fs::write(resources.join("tests/09-1.txt"), DAY_NINE_EXAMPLE).unwrap();
+    std::fs::write(resources.join("tests/09-1.expected.txt"), "50\n").unwrap();
+    dir
+}
+
+#[test]
+fn reruns_parts_and_switches_inputs() {
+    let dir = workspace();
+    std::fs::write(dir.path().join("other.txt"), "1,1\n2,1\n2,2\n1,2\n").unwrap();
+    let commands = "run\ntimings off\nexample\n1\nexample\n2\ninput other.txt\nrun\nquit\n";
+    let output = run_cli_with_stdin(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["repl", "-y", "2025", "-d", "9"],
+        commands,
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let answers = stdout
+        .lines()
+        .filter(|line| line.chars().all(|c| c.is_ascii_digit()) && !line.is_empty())
+        .collect::<Vec<_>>();
+    // Input, example part one, input part two, then the other file.
+    assert_eq!(answers, ["9", "9", "50", "9", "4", "4"], "{stdout}");
+    assert_eq!(stdout.matches("Elapsed").count(), 1, "{stdout}");
+    assert!(stdout.contains("Part one: ✔"), "{stdout}");
+    assert!(stdout.contains("Loaded example"), "{stdout}");
+}
+
+#[test]
+fn keeps_going_after_errors() {
+    let dir = workspace();
+    let output = run_cli_with_stdin(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["repl", "-y", "2025", "-d", "9"],
+        "frobnicate\ninput missing.txt\n2\n",
+    );
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown command `frobnicate`"), "{stderr}");
+    assert!(stderr.contains("Failed to read input file"), "{stderr}");
+    // The input loaded before the failed switch is still there.
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Part two:\n9"), "{stdout}");
+}
+
+#[test]
+fn combined_solvers_cannot_run_a_single_part() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_dir = dir.path().join("aoc24/resources/input");
+    std::fs::create_dir_all(&input_dir).unwrap();
+    std::fs::write(input_dir.join("01.txt"), "3   4\n4   3\n2   5\n").unwrap();
+    let output = run_cli_with_stdin(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["repl", "-y", "2024", "-d", "1"],
+        "1\nrun\n",
+    );
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot run a single part"), "{stderr}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Only `run` printed answers.
+    assert_eq!(stdout.matches("Part one:").count(), 1, "{stdout}");
+    assert!(stdout.contains("Part two:"), "{stdout}");
+}
@@ -0,0 +1,326 @@
+mod common;
+
+use common::run_cli;
+use common::run_cli_with_stdin;
+
+const DAY_ONE_INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+/// Creates a workspace layout with an input for 2024 day 1 only.
+fn workspace_with_input() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    write_input(&dir, "01.txt", DAY_ONE_INPUT);
+    dir
+}
+
+fn write_input(dir: &tempfile::TempDir, name: &str, content: &str) {
+    let input_dir = dir.path().join("aoc24/resources/input");
+    std::fs::create_dir_all(&input_dir).unwrap();
+    std::fs::write(input_dir.join(name), content).unwrap();
+}
+
+#[test]
+fn prints_json_report() {
+    let dir = workspace_with_input();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-f", "json"],
+    );
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let first = &report["solvers"][0];
+    assert_eq!(first["year"], 2024);
+    assert_eq!(first["day"], 1);
+    assert_eq!(first["status"], "succeeded");
+    assert_eq!(first["part_one"], "11");
+    assert_eq!(first["part_two"], "31");
+    assert!(first["durations"]["total_ms"].is_f64());
+    assert_eq!(report["solvers"][1]["status"], "skipped");
+
+    let summary = &report["summary"];
+    assert_eq!(summary["succeeded"], 1);
+    assert_eq!(
+        summary["skipped"],
+        summary["total_matched"].as_u64().unwrap() - 1
+    );
+}
+
+#[test]
+fn prints_csv_report() {
+    let dir = workspace_with_input();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1", "-f", "csv"],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("year,day,status,part_one,part_two,"));
+    assert!(lines[1].starts_with("2024,1,succeeded,11,31,"));
+}
+
+#[test]
+fn plain_text_without_terminal() {
+    let dir = workspace_with_input();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1"],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("===== Summary ====="));
+    assert!(stdout.contains("✔ Solution found!"));
+    assert!(
+        !stdout.contains('\u{1b}'),
+        "unexpected escape codes: {stdout:?}"
+    );
+}
+
+#[test]
+fn parallel_run_keeps_order() {
+    let dir = workspace_with_input();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-j", "4", "-f", "json"],
+    );
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ids = report["solvers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| (s["year"].as_u64().unwrap(), s["day"].as_u64().unwrap()))
+        .collect::<Vec<_>>();
+    assert!(ids.is_sorted(), "solvers out of order: {ids:?}");
+    assert_eq!(report["solvers"][0]["status"], "succeeded");
+    assert!(report["summary"]["wall_clock_ms"].is_f64());
+}
+
+#[test]
+fn reports_panics_as_failures() {
+    let dir = workspace_with_input();
+    // The robot pushes into a tile the day 15 solver does not know about.
+    write_input(&dir, "15.txt", "#x@#\n\n<\n");
+
+    for extra in [&[][..], &["--timeout", "30"]] {
+        let mut args = vec!["run", "-y", "2024", "-f", "json"];
+        args.extend(extra);
+        let output = run_cli(dir.path(), "http://127.0.0.1:1", &args);
+        assert!(output.status.success());
+        assert!(
+            output.stderr.is_empty(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let day15 = report["solvers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["day"] == 15)
+            .unwrap();
+        assert_eq!(day15["status"], "failed");
+        let error = day15["error"].as_str().unwrap();
+        assert!(error.starts_with("Solver panicked at"), "{error}");
+        assert!(error.contains("Invalid character: 'x'"), "{error}");
+
+        assert_eq!(report["summary"]["succeeded"], 1);
+        assert_eq!(report["summary"]["failed"], 1);
+    }
+}
+
+#[test]
+fn kills_solvers_after_timeout() {
+    let dir = workspace_with_input();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1", "--timeout", "0.000001"],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Timed out after"), "{stdout}");
+    assert!(stdout.contains("Timed out: 1"), "{stdout}");
+}
+
+#[test]
+fn reports_memory_usage() {
+    let dir = workspace_with_input();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1", "--memory", "-f", "json"],
+    );
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let total = &report["solvers"][0]["memory"]["total"];
+    assert!(total["allocations"].as_u64().unwrap() > 0);
+    assert!(total["peak_bytes"].as_u64().unwrap() > 0);
+    assert!(total["allocated_bytes"].as_u64() >= total["peak_bytes"].as_u64());
+}
+
+#[test]
+fn reads_input_from_stdin() {
+    let dir = tempfile::tempdir().unwrap();
+    for extra in [&[][..], &["--timeout", "30"]] {
+        let mut args = vec!["run", "-y", "2024", "-d", "1", "-i", "-", "-f", "csv"];
+        args.extend(extra);
+        let output = run_cli_with_stdin(dir.path(), "http://127.0.0.1:1", &args, DAY_ONE_INPUT);
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout
+                .lines()
+                .nth(1)
+                .unwrap()
+                .starts_with("2024,1,succeeded,11,31,")
+        );
+    }
+}
+
+#[test]
+fn runs_every_file_in_input_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let inputs = dir.path().join("inputs");
+    std::fs::create_dir_all(&inputs).unwrap();
+    std::fs::write(inputs.join("alice.txt"), DAY_ONE_INPUT).unwrap();
+    std::fs::write(inputs.join("bob.txt"), "1   1\n").unwrap();
+    std::fs::write(inputs.join("01-1.expected.txt"), "11\n").unwrap();
+    std::fs::write(inputs.join(".hidden"), "").unwrap();
+
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &[
+            "run",
+            "-y",
+            "2024",
+            "-d",
+            "1",
+            "--input-dir",
+            "inputs",
+            "-f",
+            "csv",
+        ],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let rows = stdout.lines().skip(1).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 2);
+    assert!(rows[0].starts_with("2024,1,succeeded,11,31,"));
+    assert!(rows[0].ends_with("alice.txt"));
+    assert!(rows[1].starts_with("2024,1,succeeded,0,1,"));
+    assert!(rows[1].ends_with("bob.txt"));
+}
+
+#[test]
+fn input_override_needs_single_solver() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-i", "-"],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("need a single solver"));
+}
+
+#[test]
+fn rejects_invalid_durations() {
+    let dir = workspace_with_input();
+    for value in ["-1", "0", "NaN", "inf", "1e300", "soon"] {
+        for command in ["run", "bench"] {
+            let flag = if command == "run" {
+                "--timeout"
+            } else {
+                "--time"
+            };
+            let arg = format!("{flag}={value}");
+            let output = run_cli(
+                dir.path(),
+                "http://127.0.0.1:1",
+                &[command, "-y", "2024", "-d", "1", &arg],
+            );
+            assert_eq!(output.status.code(), Some(2), "{command} {arg}");
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert!(!stderr.contains("panicked"), "{stderr}");
+        }
+    }
+}
+
+#[test]
+fn measures_memory_on_a_single_job() {
+    let dir = workspace_with_input();
+    let args = ["run", "-y", "2024", "-d", "1", "--memory"];
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &[&args[..], &["--jobs", "1"]].concat(),
+    );
+    assert!(output.status.success());
+
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &[&args[..], &["--jobs", "2"]].concat(),
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("`--memory` needs `--jobs 1`"), "{stderr}");
+}
+
+#[test]
+fn records_and_verifies_in_child_processes() {
+    let dir = workspace_with_input();
+    let args = ["-y", "2024", "-d", "1", "--timeout", "30"];
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &[&["record"], &args[..]].concat(),
+    );
+    assert!(output.status.success());
+    let answers = std::fs::read_to_string(dir.path().join("aoc24/resources/answers.toml")).unwrap();
+    assert!(answers.contains("part_one = \"11\""), "{answers}");
+
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &[&["verify"], &args[..]].concat(),
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Passed: 2"), "{stdout}");
+}
+
+#[test]
+fn warns_when_last_runs_cannot_be_saved() {
+    let dir = workspace_with_input();
+    // A file where the `.aoc` directory should be.
+    std::fs::write(dir.path().join(".aoc"), "").unwrap();
+    let output = run_cli(
+        dir.path(),
+        "http://127.0.0.1:1",
+        &["run", "-y", "2024", "-d", "1"],
+    );
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Warning: failed to update the last runs"),
+        "{stderr}"
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Succeeded: 1"), "{stdout}");
+}
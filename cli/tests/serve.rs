@@ -0,0 +1,129 @@
+mod common;
+
+use common::spawn_cli;
+use reqwest::blocking::Client;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::process::Child;
+use std::process::ChildStdout;
+
+const DAY_ONE_INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+/// A running `cli serve`, killed when dropped.
+struct Server {
+    child: Child,
+    // Kept open so that the request log does not hit a closed pipe.
+    _stdout: BufReader<ChildStdout>,
+    base_url: String,
+    http: Client,
+    _dir: tempfile::TempDir,
+}
+
+impl Server {
+    fn start(args: &[&str]) -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let args = [&["serve", "--port", "0"], args].concat();
+        let mut child = spawn_cli(dir.path(), "http://127.0.0.1:9", &args);
+        let mut stdout = BufReader::new(child.stdout.take().unwrap());
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        let base_url = line
+            .trim()
+            .strip_prefix("Listening on ")
+            .unwrap_or_else(|| panic!("unexpected output: {line}"))
+            .to_string();
+        Self {
+            child,
+            _stdout: stdout,
+            base_url,
+            http: Client::new(),
+            _dir: dir,
+        }
+    }
+
+    fn get(&self, path: &str) -> (u16, serde_json::Value) {
+        let response = self
+            .http
+            .get(format!("{}{path}", self.base_url))
+            .send()
+            .unwrap();
+        let status = response.status().as_u16();
+        (
+            status,
+            serde_json::from_str(&response.text().unwrap()).unwrap(),
+        )
+    }
+
+    fn post(&self, path: &str, body: &str) -> (u16, serde_json::Value) {
+        let response = self
+            .http
+            .post(format!("{}{path}", self.base_url))
+            .body(body.to_string())
+            .send()
+            .unwrap();
+        let status = response.status().as_u16();
+        (
+            status,
+            serde_json::from_str(&response.text().unwrap()).unwrap(),
+        )
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn lists_and_runs_solvers() {
+    let server = Server::start(&[]);
+
+    let (status, solvers) = server.get("/solvers");
+    assert_eq!(status, 200);
+    let solvers = solvers.as_array().unwrap();
+    assert!(solvers.contains(&serde_json::json!({ "year": 2024, "day": 1 })));
+
+    let (status, report) = server.post("/solve/2024/1", DAY_ONE_INPUT);
+    assert_eq!(status, 200, "{report}");
+    assert_eq!(report["status"], "succeeded");
+    assert_eq!(report["part_one"], "11");
+    assert_eq!(report["part_two"], "31");
+    assert!(report["durations"]["total_ms"].is_number());
+
+    let (status, report) = server.post("/solve/2024/1", "not an input");
+    assert_eq!(status, 422, "{report}");
+    assert_eq!(report["status"], "failed");
+}
+
+#[test]
+fn rejects_bad_requests() {
+    let server = Server::start(&["--max-input-bytes", "16"]);
+
+    let (status, body) = server.post("/solve/2024/99", "");
+    assert_eq!(status, 404);
+    assert!(body["error"].as_str().unwrap().contains("No solver"));
+
+    let (status, _) = server.post("/solve/twenty/1", "");
+    assert_eq!(status, 400);
+
+    let (status, body) = server.post("/solve/2024/1", DAY_ONE_INPUT);
+    assert_eq!(status, 413);
+    assert!(body["error"].as_str().unwrap().contains("16 bytes"));
+
+    let (status, _) = server.get("/solve/2024/1");
+    assert_eq!(status, 405);
+
+    let (status, _) = server.get("/nothing");
+    assert_eq!(status, 404);
+}
+
+#[test]
+fn times_out_slow_solvers() {
+    let server = Server::start(&["--timeout", "0.000001"]);
+
+    let (status, report) = server.post("/solve/2024/1", DAY_ONE_INPUT);
+    assert_eq!(status, 504, "{report}");
+    assert_eq!(report["status"], "timed_out");
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use common::run_cli;
+
+const DAY_ONE_INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+fn stdout(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn day_row(stdout: &str, day: u8) -> &str {
+    stdout
+        .lines()
+        .find(|line| line.trim_start().starts_with(&format!("{day} ")))
+        .unwrap_or_else(|| panic!("no row for day {day}:\n{stdout}"))
+}
+
+#[test]
+fn shows_progress_of_each_day() {
+    let dir = tempfile::tempdir().unwrap();
+    let resources = dir.path().join("aoc24/resources");
+    std::fs::create_dir_all(resources.join("input")).unwrap();
+    std::fs::create_dir_all(resources.join("tests")).unwrap();
+    std::fs::write(resources.join("input/01.txt"), DAY_ONE_INPUT).unwrap();
+    std::fs::write(resources.join("tests/01.txt"), DAY_ONE_INPUT).unwrap();
+    std::fs::write(resources.join("tests/01-2.txt"), DAY_ONE_INPUT).unwrap();
+    std::fs::write(
+        resources.join("answers.toml"),
+        "[01]\npart_one = \"11\"\npart_two = \"30\"\n\n[02]\npart_one = \"2\"\n",
+    )
+    .unwrap();
+
+    let before = run_cli(dir.path(), "http://127.0.0.1:1", &["status", "-y", "2024"]);
+    assert!(before.status.success());
+    let before = stdout(&before);
+    assert!(before.contains("Advent of Code 2024"), "{before}");
+    assert!(day_row(&before, 1).ends_with("2         ? ?"), "{before}");
+
+    // Runs on other inputs are not the day's last run.
+    let other = dir.path().join("other.txt");
+    std::fs::write(&other, "1   1\n").unwrap();
+    let args = [
+        "run",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--input",
+        other.to_str().unwrap(),
+    ];
+    assert!(
+        run_cli(dir.path(), "http://127.0.0.1:1", &args)
+            .status
+            .success()
+    );
+    let args = ["run", "-y", "2024"];
+    assert!(
+        run_cli(dir.path(), "http://127.0.0.1:1", &args)
+            .status
+            .success()
+    );
+
+    let after = run_cli(dir.path(), "http://127.0.0.1:1", &["status", "-y", "2024"]);
+    let after = stdout(&after);
+    let row = day_row(&after, 1);
+    assert!(
+        row.starts_with("  1  ✔       ✔      2         ✔ ✘      "),
+        "{after}"
+    );
+    assert!(!row.ends_with("✘"), "no time in {row}");
+    assert_eq!(day_row(&after, 2), "  2  ✔       ·      ·         ? ·");
+    assert!(after.contains("1/50 parts verified"), "{after}");
+}
+
+#[test]
+fn verify_and_record_update_the_last_runs() {
+    let dir = tempfile::tempdir().unwrap();
+    let resources = dir.path().join("aoc24/resources");
+    std::fs::create_dir_all(resources.join("input")).unwrap();
+    std::fs::write(resources.join("input/01.txt"), DAY_ONE_INPUT).unwrap();
+    std::fs::write(resources.join("answers.toml"), "[01]\npart_one = \"11\"\n").unwrap();
+
+    let args = ["verify", "-y", "2024", "-d", "1"];
+    assert!(
+        run_cli(dir.path(), "http://127.0.0.1:1", &args)
+            .status
+            .success()
+    );
+    let status = run_cli(dir.path(), "http://127.0.0.1:1", &["status", "-y", "2024"]);
+    let status = stdout(&status);
+    let row = day_row(&status, 1);
+    assert!(row.contains("✔ ·"), "{status}");
+    assert!(!row.ends_with('·'), "no time in {row}");
+
+    let args = ["record", "-y", "2024", "-d", "1"];
+    assert!(
+        run_cli(dir.path(), "http://127.0.0.1:1", &args)
+            .status
+            .success()
+    );
+    let status = run_cli(dir.path(), "http://127.0.0.1:1", &["status", "-y", "2024"]);
+    let status = stdout(&status);
+    assert!(day_row(&status, 1).contains("✔ ✔"), "{status}");
+}
+
+#[test]
+fn warns_about_unreadable_last_runs() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join(".aoc")).unwrap();
+    std::fs::write(dir.path().join(".aoc/last-runs.json"), "not json").unwrap();
+
+    let output = run_cli(dir.path(), "http://127.0.0.1:1", &["status", "-y", "2024"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Warning: failed to read the last runs"),
+        "{stderr}"
+    );
+    assert!(stdout(&output).contains("Advent of Code 2024"));
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use common::MockServer;
+use common::run_cli;
+
+fn verdict_page(message: &str) -> String {
+    format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+}
+
+#[test]
+fn submits_and_logs_guesses() {
+    let server = MockServer::start(|request| {
+        let message = if request.body.contains("answer=42") {
+            "That's the right answer! You are one gold star closer."
+        } else {
+            "That's not the right answer; your answer is too high."
+        };
+        (200, verdict_page(message))
+    });
+    let dir = tempfile::tempdir().unwrap();
+    let submit = |answer: &str| {
+        run_cli(
+            dir.path(),
+            &server.base_url,
+            &["submit", "-y", "2024", "-d", "1", "-p", "1", answer],
+        )
+    };
+
+    assert!(submit("100").status.success());
+    let refused = submit("150");
+    assert!(!refused.status.success());
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("too high"));
+    assert!(submit("42").status.success());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2024/day/1/answer");
+    assert_eq!(requests[0].body, "level=1&answer=100");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+
+    let history =
+        std::fs::read_to_string(dir.path().join("aoc24/resources/submissions.toml")).unwrap();
+    assert!(history.contains(r#"kind = "too_high""#));
+    assert!(history.contains(r#"kind = "correct""#));
+}
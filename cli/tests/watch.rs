@@ -0,0 +1,81 @@
+mod common;
+
+use common::spawn_cli;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::process::Child;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+const DAY_ONE_INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+/// A running `cli watch`, killed when dropped.
+struct Watcher {
+    child: Child,
+    lines: Receiver<String>,
+}
+
+impl Watcher {
+    fn start(dir: &std::path::Path, args: &[&str]) -> Self {
+        let mut child = spawn_cli(dir, "http://127.0.0.1:1", args);
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let (sender, lines) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { child, lines }
+    }
+
+    /// Reads lines until one contains `text`, returning everything read.
+    fn wait_for(&self, text: &str) -> String {
+        let mut output = String::new();
+        loop {
+            let line = self
+                .lines
+                .recv_timeout(Duration::from_secs(10))
+                .unwrap_or_else(|_| panic!("no `{text}` in output:\n{output}"));
+            output.push_str(&line);
+            output.push('\n');
+            if line.contains(text) {
+                return output;
+            }
+        }
+    }
+}
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn reruns_when_the_input_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("aoc24/resources/input/01.txt");
+    std::fs::create_dir_all(input.parent().unwrap()).unwrap();
+    std::fs::write(&input, DAY_ONE_INPUT).unwrap();
+
+    let watcher = Watcher::start(
+        dir.path(),
+        &["watch", "-y", "2024", "-d", "1", "--no-build"],
+    );
+    let first = watcher.wait_for("Time");
+    assert!(first.contains("Part one: 11\n"), "{first}");
+    assert!(first.contains("Part two: 31\n"), "{first}");
+
+    std::fs::write(&input, "1   1\n").unwrap();
+    let second = watcher.wait_for("Time");
+    assert!(second.contains("Changed"), "{second}");
+    assert!(second.contains("Part one: 0 (was 11)"), "{second}");
+    assert!(second.contains("Part two: 1 (was 31)"), "{second}");
+
+    std::fs::remove_file(&input).unwrap();
+    let third = watcher.wait_for("No input");
+    assert!(third.contains("01.txt"), "{third}");
+}
@@ -0,0 +1,362 @@
+use crate::RunOptions;
+use crate::config;
+use crate::report::RunReport;
+use anyhow::Context;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::btree_map::Entry;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use utils::solution::Solution;
+use utils::solution::Solver;
+
+/// Recorded answers for a single day. Either part may be missing, e.g. when
+/// only part one has been solved so far.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+/// All recorded answers for a year, stored as `aocYY/resources/answers.toml`
+/// with one table per day:
+///
+/// ```toml
+/// [01]
+/// part_one = "1234"
+/// part_two = "5678"
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct AnswerBook {
+    #[serde(flatten)]
+    days: BTreeMap<String, DayAnswers>,
+}
+
+impl AnswerBook {
+    pub fn path_for_year(year: u16) -> PathBuf {
+        config::workspace().answers_path(year)
+    }
+
+    /// Loads the answers file at `path`, returning an empty book if it does not exist.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read answers file {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse answers file {}", path.display()))
+    }
+
+    pub fn load_for_year(year: u16) -> anyhow::Result<Self> {
+        Self::load(&Self::path_for_year(year))
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DayAnswers> {
+        self.days.get(&day_key(day))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let content = toml::to_string(self)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write answers file {}", path.display()))
+    }
+
+    /// Records both parts of `solution` for `day`. An existing answer that
+    /// differs from the new one is only replaced when `force` is set.
+    pub fn record(&mut self, day: u8, solution: &Solution, force: bool) -> RecordOutcome {
+        let entry = self.days.entry(day_key(day)).or_default();
+        let new = DayAnswers {
+            part_one: Some(solution.part_one.clone()),
+            part_two: Some(solution.part_two.clone()),
+        };
+
+        if *entry == new {
+            return RecordOutcome::Unchanged;
+        }
+
+        let conflicts = |old: &Option<String>, new: &Option<String>| {
+            old.as_ref().is_some_and(|old| Some(old) != new.as_ref())
+        };
+        let conflicting =
+            conflicts(&entry.part_one, &new.part_one) || conflicts(&entry.part_two, &new.part_two);
+
+        if conflicting && !force {
+            return RecordOutcome::Conflict(entry.clone());
+        }
+
+        let outcome = if conflicting {
+            RecordOutcome::Overwritten(entry.clone())
+        } else {
+            RecordOutcome::Added
+        };
+        *entry = new;
+        outcome
+    }
+}
+
+/// Result of recording a day's answers into an [`AnswerBook`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordOutcome {
+    /// New answers were stored where none (or only some) existed.
+    Added,
+    /// The recorded answers already match.
+    Unchanged,
+    /// Different answers were replaced because `force` was set. Holds the old answers.
+    Overwritten(DayAnswers),
+    /// Different answers exist and were kept. Holds the existing answers.
+    Conflict(DayAnswers),
+}
+
+fn day_key(day: u8) -> String {
+    format!("{day:02}")
+}
+
+/// Outcome of comparing a computed answer against the recorded one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn compare(expected: Option<&str>, actual: &str) -> Self {
+        match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Verdict::Pass => "✔",
+            Verdict::Fail => "✘",
+            Verdict::Unknown => "?",
+        };
+        f.pad(symbol)
+    }
+}
+
+/// Compares both parts of `solution` against `expected`.
+pub fn verify_solution(expected: Option<&DayAnswers>, solution: &Solution) -> (Verdict, Verdict) {
+    let part_one = Verdict::compare(
+        expected.and_then(|a| a.part_one.as_deref()),
+        &solution.part_one,
+    );
+    let part_two = Verdict::compare(
+        expected.and_then(|a| a.part_two.as_deref()),
+        &solution.part_two,
+    );
+    (part_one, part_two)
+}
+
+/// A solver run on its default input, checked against the recorded answers.
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub report: RunReport,
+    pub expected: Option<DayAnswers>,
+    /// Verdicts for both parts, only known when the solver succeeded.
+    pub verdicts: Option<(Verdict, Verdict)>,
+}
+
+/// Runs `solvers` on their default inputs and compares their answers with the
+/// recorded ones. Reports keep the order of `solvers`.
+pub fn verify(
+    solvers: &[&'static Solver],
+    options: &RunOptions,
+) -> anyhow::Result<Vec<Verification>> {
+    let batch = crate::run_all(&crate::default_inputs(solvers), options)?;
+    let mut books = Books::default();
+    batch
+        .solvers
+        .into_iter()
+        .map(|report| {
+            let expected = books.get(report.year)?.get(report.day).cloned();
+            let verdicts = report
+                .solution()
+                .map(|solution| verify_solution(expected.as_ref(), &solution));
+            Ok(Verification {
+                report,
+                expected,
+                verdicts,
+            })
+        })
+        .collect()
+}
+
+/// A solver run on its default input whose answers were recorded.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    pub report: RunReport,
+    /// Only known when the solver succeeded.
+    pub outcome: Option<RecordOutcome>,
+}
+
+/// What [`record`] did.
+#[derive(Debug, Clone)]
+pub struct RecordReport {
+    /// One recording per solver, in the order of the solvers.
+    pub recordings: Vec<Recording>,
+    /// Answers files that were written because their answers changed.
+    pub written: Vec<PathBuf>,
+}
+
+/// Runs `solvers` on their default inputs and records their answers, see
+/// [`AnswerBook::record`]. Only the answers files of changed years are written.
+pub fn record(
+    solvers: &[&'static Solver],
+    force: bool,
+    options: &RunOptions,
+) -> anyhow::Result<RecordReport> {
+    let batch = crate::run_all(&crate::default_inputs(solvers), options)?;
+    let mut books = Books::default();
+    let mut changed_years = BTreeSet::new();
+    let mut recordings = Vec::new();
+    for report in batch.solvers {
+        let outcome = match report.solution() {
+            Some(solution) => {
+                let outcome = books.get(report.year)?.record(report.day, &solution, force);
+                if matches!(
+                    outcome,
+                    RecordOutcome::Added | RecordOutcome::Overwritten(_)
+                ) {
+                    changed_years.insert(report.year);
+                }
+                Some(outcome)
+            }
+            None => None,
+        };
+        recordings.push(Recording { report, outcome });
+    }
+
+    let mut written = Vec::new();
+    for year in changed_years {
+        let path = AnswerBook::path_for_year(year);
+        books.get(year)?.save(&path)?;
+        written.push(path);
+    }
+    Ok(RecordReport {
+        recordings,
+        written,
+    })
+}
+
+/// Answer books loaded on first use.
+#[derive(Default)]
+struct Books(BTreeMap<u16, AnswerBook>);
+
+impl Books {
+    fn get(&mut self, year: u16) -> anyhow::Result<&mut AnswerBook> {
+        Ok(match self.0.entry(year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(AnswerBook::load_for_year(year)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnswerBook;
+    use super::DayAnswers;
+    use super::RecordOutcome;
+    use super::Verdict;
+    use super::verify_solution;
+    use utils::solution::Solution;
+
+    #[test]
+    fn parse_answer_book() {
+        let book: AnswerBook = toml::from_str(
+            r#"
+            [01]
+            part_one = "11"
+            part_two = "31"
+
+            [12]
+            part_one = "abc"
+            "#,
+        )
+        .unwrap();
+
+        let day_one = book.get(1).unwrap();
+        assert_eq!(day_one.part_one.as_deref(), Some("11"));
+        assert_eq!(day_one.part_two.as_deref(), Some("31"));
+
+        let day_twelve = book.get(12).unwrap();
+        assert_eq!(day_twelve.part_one.as_deref(), Some("abc"));
+        assert_eq!(day_twelve.part_two, None);
+
+        assert!(book.get(2).is_none());
+    }
+
+    #[test]
+    fn verify_parts() {
+        let expected = DayAnswers {
+            part_one: Some("11".into()),
+            part_two: None,
+        };
+        let solution = Solution {
+            part_one: "11".into(),
+            part_two: "31".into(),
+        };
+        assert_eq!(
+            verify_solution(Some(&expected), &solution),
+            (Verdict::Pass, Verdict::Unknown)
+        );
+
+        let wrong = Solution {
+            part_one: "12".into(),
+            part_two: "31".into(),
+        };
+        assert_eq!(
+            verify_solution(Some(&expected), &wrong),
+            (Verdict::Fail, Verdict::Unknown)
+        );
+        assert_eq!(
+            verify_solution(None, &solution),
+            (Verdict::Unknown, Verdict::Unknown)
+        );
+    }
+
+    #[test]
+    fn record_answers() {
+        let mut book = AnswerBook::default();
+        let solution = Solution {
+            part_one: "11".into(),
+            part_two: "31".into(),
+        };
+        assert_eq!(book.record(1, &solution, false), RecordOutcome::Added);
+        assert_eq!(book.record(1, &solution, false), RecordOutcome::Unchanged);
+
+        let different = Solution {
+            part_one: "12".into(),
+            part_two: "31".into(),
+        };
+        let RecordOutcome::Conflict(_) = book.record(1, &different, false) else {
+            panic!("Expected a conflict");
+        };
+        assert_eq!(book.get(1).unwrap().part_one.as_deref(), Some("11"));
+
+        let RecordOutcome::Overwritten(old) = book.record(1, &different, true) else {
+            panic!("Expected an overwrite");
+        };
+        assert_eq!(old.part_one.as_deref(), Some("11"));
+        assert_eq!(book.get(1).unwrap().part_one.as_deref(), Some("12"));
+
+        let serialized = toml::to_string(&book).unwrap();
+        let reloaded: AnswerBook = toml::from_str(&serialized).unwrap();
+        assert_eq!(reloaded.get(1), book.get(1));
+    }
+}
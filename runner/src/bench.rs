@@ -0,0 +1,119 @@
+use crate::report::Status;
+use crate::stats::Stats;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+use utils::solution::PhaseMemory;
+use utils::solution::PhaseTimings;
+use utils::solution::Solver;
+
+/// How [`bench`] samples a solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling.
+    pub warmup: usize,
+    /// Number of timed runs, unless a `budget` is given.
+    pub samples: usize,
+    /// Keep sampling until this much time has been spent, instead of taking
+    /// a fixed number of samples.
+    pub budget: Option<Duration>,
+}
+
+/// Timings of a solver sampled on its default input.
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub input: PathBuf,
+    /// Never [`Status::TimedOut`], since benchmarks run in-process.
+    pub status: Status,
+    pub timings: Vec<PhaseTimings>,
+    pub error: Option<String>,
+}
+
+impl BenchReport {
+    /// Statistics over the total time of each sample.
+    pub fn total(&self) -> Option<Stats> {
+        let totals = self.timings.iter().map(|t| t.total).collect::<Vec<_>>();
+        Stats::from_samples(&totals)
+    }
+
+    /// Statistics over each phase, named as in [`PhaseTimings::phases`]. Phases
+    /// that were not measured are left out.
+    pub fn phases(&self) -> Vec<(usize, &'static str, Stats)> {
+        let names = PhaseTimings::default().phases().map(|(name, _)| name);
+        names
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, name)| {
+                let durations = self
+                    .timings
+                    .iter()
+                    .filter_map(|t| t.phases()[index].1)
+                    .collect::<Vec<_>>();
+                Stats::from_samples(&durations).map(|stats| (index, name, stats))
+            })
+            .collect()
+    }
+
+    /// Heap usage of the last sample. After warm-up, allocations barely vary
+    /// between runs, so it stands for all of them.
+    pub fn memory(&self) -> Option<PhaseMemory> {
+        self.timings.last().and_then(|t| t.memory)
+    }
+}
+
+/// Samples `solver` on its default input. A missing input is skipped and a
+/// failed or panicking run stops the sampling.
+pub fn bench(solver: &Solver, options: &BenchOptions) -> BenchReport {
+    let input = crate::default_input_path(solver);
+    let report = |status, timings, error| BenchReport {
+        year: solver.year,
+        day: solver.day,
+        input: input.clone(),
+        status,
+        timings,
+        error,
+    };
+    if !crate::input_exists(&input) {
+        return report(Status::Skipped, Vec::new(), None);
+    }
+    let sampled = crate::read_input(&input).and_then(|content| sample(solver, &content, options));
+    match sampled {
+        Ok(timings) => report(Status::Succeeded, timings, None),
+        Err(err) => report(Status::Failed, Vec::new(), Some(err.to_string())),
+    }
+}
+
+/// Runs `solver` `options.warmup` times untimed, then times it
+/// `options.samples` times or, if a budget is given, until the budget is spent.
+pub fn sample(
+    solver: &Solver,
+    input: &str,
+    options: &BenchOptions,
+) -> anyhow::Result<Vec<PhaseTimings>> {
+    let run = || -> anyhow::Result<PhaseTimings> {
+        let (_, timings) = crate::isolation::catch_panic(|| solver.solve_timed(input))?;
+        Ok(timings)
+    };
+
+    for _ in 0..options.warmup {
+        run()?;
+    }
+
+    let mut timings = Vec::new();
+    match options.budget {
+        Some(budget) => {
+            let start_time = Instant::now();
+            while timings.is_empty() || start_time.elapsed() < budget {
+                timings.push(run()?);
+            }
+        }
+        None => {
+            for _ in 0..options.samples.max(1) {
+                timings.push(run()?);
+            }
+        }
+    }
+    Ok(timings)
+}
@@ -0,0 +1,63 @@
+use std::time::Duration;
+use std::time::Instant;
+
+/// Wall-clock and CPU time spent since a [`Stopwatch`] was started.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Elapsed {
+    pub wall: Duration,
+    /// User and system time of this process and of the child processes it
+    /// waited for. `None` where the platform does not report it.
+    pub cpu: Option<Duration>,
+}
+
+/// Measures a batch of runs, solvers on other threads and in child processes
+/// included.
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    wall: Instant,
+    cpu: Option<Duration>,
+}
+
+impl Stopwatch {
+    pub fn start() -> Self {
+        Self {
+            wall: Instant::now(),
+            cpu: process_cpu_time(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Elapsed {
+        Elapsed {
+            wall: self.wall.elapsed(),
+            cpu: self
+                .cpu
+                .zip(process_cpu_time())
+                .map(|(start, end)| end.saturating_sub(start)),
+        }
+    }
+}
+
+/// CPU time used so far by this process and its reaped children.
+#[cfg(unix)]
+fn process_cpu_time() -> Option<Duration> {
+    fn usage(who: libc::c_int) -> Option<Duration> {
+        // SAFETY: `getrusage` only writes to the struct it is given.
+        let usage = unsafe {
+            let mut usage = std::mem::zeroed::<libc::rusage>();
+            if libc::getrusage(who, &mut usage) != 0 {
+                return None;
+            }
+            usage
+        };
+        let duration = |time: libc::timeval| {
+            Duration::from_secs(time.tv_sec as u64) + Duration::from_micros(time.tv_usec as u64)
+        };
+        Some(duration(usage.ru_utime) + duration(usage.ru_stime))
+    }
+    Some(usage(libc::RUSAGE_SELF)? + usage(libc::RUSAGE_CHILDREN)?)
+}
+
+#[cfg(not(unix))]
+fn process_cpu_time() -> Option<Duration> {
+    None
+}
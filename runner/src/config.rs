@@ -0,0 +1,316 @@
+use anyhow::Context;
+use serde::Deserialize;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Settings read from `aoc.toml`. Paths are relative to the directory holding
+/// the file and may use `{year}` (e.g. 2024) and `{yy}` (e.g. 24):
+///
+/// ```toml
+/// input_dir = "../private-inputs/{year}"
+/// default_year = 2025
+/// session_file = "~/.config/aoc/session"
+/// ```
+///
+/// Every setting is optional; the defaults describe the layout of this repository.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub input_dir: String,
+    /// Where `inputs encrypt` and `get-input` store encrypted inputs. Unlike
+    /// `input_dir`, it is meant to be committed.
+    pub encrypted_input_dir: String,
+    /// Where `get-examples` and `new` save example inputs. `load_test_input!`
+    /// always reads the year crate's `resources/tests`, so solver tests only
+    /// see examples saved there.
+    pub tests_dir: String,
+    pub puzzles_dir: String,
+    pub answers_file: String,
+    pub submissions_file: String,
+    /// Year used by commands that need one when `--year` is not given.
+    pub default_year: Option<u16>,
+    /// Environment variable (or `.env` entry) holding the session token.
+    pub session_env: String,
+    /// File holding the session token. Takes precedence over `session_env`.
+    pub session_file: Option<String>,
+    /// Advent of Code endpoint. `AOC_BASE_URL` still takes precedence.
+    pub base_url: Option<String>,
+    /// User-Agent sent with every request. Advent of Code asks automated tools
+    /// to identify themselves with a way to contact their user.
+    pub user_agent: Option<String>,
+    /// Minimum time between two requests to Advent of Code, in milliseconds.
+    pub request_interval_ms: u64,
+    /// Where the time of the last request is kept, so that the interval also
+    /// holds across invocations.
+    pub throttle_file: String,
+    /// Where the last run of each day on its default input is kept, for `status`.
+    pub last_runs_file: String,
+    /// Environment variable (or `.env` entry) holding the input passphrase.
+    pub input_key_env: String,
+    /// File holding the input passphrase. Takes precedence over `input_key_env`.
+    pub input_key_file: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            input_dir: "aoc{yy}/resources/input".to_string(),
+            encrypted_input_dir: "aoc{yy}/resources/input-encrypted".to_string(),
+            tests_dir: "aoc{yy}/resources/tests".to_string(),
+            puzzles_dir: "aoc{yy}/resources/puzzles".to_string(),
+            answers_file: "aoc{yy}/resources/answers.toml".to_string(),
+            submissions_file: "aoc{yy}/resources/submissions.toml".to_string(),
+            default_year: None,
+            session_env: "AOC_SESSION".to_string(),
+            session_file: None,
+            base_url: None,
+            user_agent: None,
+            request_interval_ms: 3_000,
+            throttle_file: ".aoc/last-request".to_string(),
+            last_runs_file: ".aoc/last-runs.json".to_string(),
+            input_key_env: "AOC_INPUT_KEY".to_string(),
+            input_key_file: None,
+        }
+    }
+}
+
+/// The workspace root and its configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    pub root: PathBuf,
+    pub config: Config,
+}
+
+impl Workspace {
+    /// Finds the workspace root by walking up from `start`: the nearest
+    /// directory with an `aoc.toml`, otherwise the nearest Cargo workspace,
+    /// otherwise `start` itself. The root is kept as `.` when it is `start`, so
+    /// paths print the way they always have.
+    pub fn discover(start: &Path) -> anyhow::Result<Self> {
+        let start = start.canonicalize()?;
+        let relative_to_start = |dir: &Path| {
+            if dir == start {
+                PathBuf::from(".")
+            } else {
+                dir.to_path_buf()
+            }
+        };
+
+        if let Some(dir) = start
+            .ancestors()
+            .find(|dir| dir.join(CONFIG_FILE).is_file())
+        {
+            let path = dir.join(CONFIG_FILE);
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let config = toml::from_str(&content)
+                .with_context(|| format!("Failed to parse {}", path.display()))?;
+            return Ok(Self {
+                root: relative_to_start(dir),
+                config,
+            });
+        }
+
+        let is_cargo_workspace = |dir: &Path| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        };
+        let root = start.ancestors().find(|dir| is_cargo_workspace(dir));
+        Ok(Self {
+            root: relative_to_start(root.unwrap_or(&start)),
+            config: Config::default(),
+        })
+    }
+
+    /// Resolves `relative` against the workspace root.
+    pub fn path(&self, relative: impl AsRef<Path>) -> PathBuf {
+        self.root.join(relative)
+    }
+
+    fn year_path(&self, template: &str, year: u16) -> PathBuf {
+        let relative = template
+            .replace("{year}", &year.to_string())
+            .replace("{yy}", &format!("{:02}", year % 100));
+        self.path(expand_home(&relative))
+    }
+
+    pub fn input_dir(&self, year: u16) -> PathBuf {
+        self.year_path(&self.config.input_dir, year)
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.input_dir(year).join(format!("{day:02}.txt"))
+    }
+
+    pub fn encrypted_input_dir(&self, year: u16) -> PathBuf {
+        self.year_path(&self.config.encrypted_input_dir, year)
+    }
+
+    pub fn encrypted_input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.encrypted_input_dir(year)
+            .join(format!("{day:02}.txt.{}", crate::inputs::EXTENSION))
+    }
+
+    pub fn tests_dir(&self, year: u16) -> PathBuf {
+        self.year_path(&self.config.tests_dir, year)
+    }
+
+    pub fn puzzles_dir(&self, year: u16) -> PathBuf {
+        self.year_path(&self.config.puzzles_dir, year)
+    }
+
+    pub fn answers_path(&self, year: u16) -> PathBuf {
+        self.year_path(&self.config.answers_file, year)
+    }
+
+    pub fn submissions_path(&self, year: u16) -> PathBuf {
+        self.year_path(&self.config.submissions_file, year)
+    }
+
+    /// Reads the session token from `session_file` if set, otherwise from the
+    /// `session_env` variable.
+    pub fn session_token(&self) -> anyhow::Result<String> {
+        if let Some(file) = &self.config.session_file {
+            let path = self.path(expand_home(file));
+            let token = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read session token from {}", path.display()))?;
+            return Ok(token.trim().to_string());
+        }
+        let variable = &self.config.session_env;
+        std::env::var(variable)
+            .map_err(|_| anyhow::anyhow!("Could not find {variable} in the environment or `.env`."))
+    }
+
+    pub fn throttle_path(&self) -> PathBuf {
+        self.path(expand_home(&self.config.throttle_file))
+    }
+
+    pub fn last_runs_path(&self) -> PathBuf {
+        self.path(expand_home(&self.config.last_runs_file))
+    }
+
+    /// Reads the input passphrase from `input_key_file` if set, otherwise from
+    /// the `input_key_env` variable. `None` when neither is available.
+    pub fn input_passphrase(&self) -> anyhow::Result<Option<String>> {
+        if let Some(file) = &self.config.input_key_file {
+            let path = self.path(expand_home(file));
+            let passphrase = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read input key from {}", path.display()))?;
+            return Ok(Some(passphrase.trim().to_string()));
+        }
+        Ok(std::env::var(&self.config.input_key_env)
+            .ok()
+            .filter(|passphrase| !passphrase.is_empty()))
+    }
+
+    /// `AOC_BASE_URL` if set, then `base_url` from the config, then the real site.
+    pub fn base_url(&self) -> Option<String> {
+        std::env::var("AOC_BASE_URL")
+            .ok()
+            .or_else(|| self.config.base_url.clone())
+    }
+}
+
+/// Replaces a leading `~/` with the home directory.
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+static WORKSPACE: OnceLock<Workspace> = OnceLock::new();
+
+/// Discovers the workspace from the current directory. Called once at startup
+/// so that a broken `aoc.toml` is reported before anything runs.
+pub fn init() -> anyhow::Result<()> {
+    let workspace = Workspace::discover(&std::env::current_dir()?)?;
+    WORKSPACE.get_or_init(|| workspace);
+    Ok(())
+}
+
+/// The workspace found by [`init`], or the default layout in the current directory.
+pub fn workspace() -> &'static Workspace {
+    WORKSPACE.get_or_init(|| Workspace {
+        root: PathBuf::from("."),
+        config: Config::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CONFIG_FILE;
+    use super::Config;
+    use super::Workspace;
+    use std::path::PathBuf;
+
+    #[test]
+    fn default_layout() {
+        let workspace = Workspace {
+            root: PathBuf::from("."),
+            config: Config::default(),
+        };
+        assert_eq!(
+            workspace.input_path(2024, 1),
+            PathBuf::from("./aoc24/resources/input/01.txt")
+        );
+        assert_eq!(
+            workspace.encrypted_input_path(2024, 1),
+            PathBuf::from("./aoc24/resources/input-encrypted/01.txt.enc")
+        );
+        assert_eq!(
+            workspace.answers_path(2025),
+            PathBuf::from("./aoc25/resources/answers.toml")
+        );
+    }
+
+    #[test]
+    fn discover_config() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(CONFIG_FILE),
+            "input_dir = \"inputs/{year}\"\ndefault_year = 2024\n",
+        )
+        .unwrap();
+        let nested = dir.path().join("a/b");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let workspace = Workspace::discover(&nested).unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        assert_eq!(workspace.root, root);
+        assert_eq!(workspace.config.default_year, Some(2024));
+        assert_eq!(
+            workspace.input_path(2024, 3),
+            root.join("inputs/2024/03.txt")
+        );
+        assert_eq!(
+            workspace.tests_dir(2024),
+            root.join("aoc24/resources/tests")
+        );
+
+        let at_root = Workspace::discover(dir.path()).unwrap();
+        assert_eq!(at_root.root, PathBuf::from("."));
+    }
+
+    #[test]
+    fn reject_unknown_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(CONFIG_FILE), "input_dirs = \"x\"\n").unwrap();
+        assert!(Workspace::discover(dir.path()).is_err());
+    }
+
+    #[test]
+    fn discover_cargo_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "[workspace]\nmembers = []\n").unwrap();
+        let nested = dir.path().join("cli/src");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let workspace = Workspace::discover(&nested).unwrap();
+        assert_eq!(workspace.root, dir.path().canonicalize().unwrap());
+        assert_eq!(workspace.config, Config::default());
+    }
+}
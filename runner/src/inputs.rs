@@ -0,0 +1,215 @@
+use crate::config;
+use anyhow::Context;
+use argon2::Argon2;
+use chacha20poly1305::AeadCore;
+use chacha20poly1305::ChaCha20Poly1305;
+use chacha20poly1305::Key;
+use chacha20poly1305::KeyInit;
+use chacha20poly1305::Nonce;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::aead::rand_core::RngCore;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+/// Extension of encrypted inputs, e.g. `01.txt.enc`.
+pub const EXTENSION: &str = "enc";
+
+const MAGIC: &[u8] = b"AOCINPUT1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Encrypts inputs with ChaCha20-Poly1305 under a key derived from a
+/// passphrase with Argon2id. A blob is laid out as
+/// `MAGIC | salt | nonce | ciphertext`.
+///
+/// Key derivation is deliberately slow, so derived keys are cached per salt and
+/// every blob written by one `Cipher` shares the same salt.
+pub struct Cipher {
+    passphrase: String,
+    salt: [u8; SALT_LEN],
+    keys: Mutex<HashMap<[u8; SALT_LEN], Key>>,
+}
+
+impl Cipher {
+    pub fn new(passphrase: String) -> Self {
+        let mut salt = [0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        Self {
+            passphrase,
+            salt,
+            keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> anyhow::Result<Vec<u8>> {
+        let cipher = ChaCha20Poly1305::new(&self.key(self.salt)?);
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt input"))?;
+
+        let mut blob = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+        blob.extend_from_slice(MAGIC);
+        blob.extend_from_slice(&self.salt);
+        blob.extend_from_slice(&nonce);
+        blob.extend_from_slice(&ciphertext);
+        Ok(blob)
+    }
+
+    pub fn decrypt(&self, blob: &[u8]) -> anyhow::Result<String> {
+        let rest = blob
+            .strip_prefix(MAGIC)
+            .context("Not an encrypted input (unknown header)")?;
+        anyhow::ensure!(
+            rest.len() >= SALT_LEN + NONCE_LEN,
+            "Encrypted input is truncated"
+        );
+        let (salt, rest) = rest.split_at(SALT_LEN);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+        let cipher = ChaCha20Poly1305::new(&self.key(salt.try_into()?)?);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                anyhow::anyhow!("Failed to decrypt input: wrong passphrase or corrupted file")
+            })?;
+        Ok(String::from_utf8(plaintext)?)
+    }
+
+    fn key(&self, salt: [u8; SALT_LEN]) -> anyhow::Result<Key> {
+        let mut keys = self.keys.lock().unwrap();
+        if let Some(key) = keys.get(&salt) {
+            return Ok(*key);
+        }
+        let mut key = Key::default();
+        Argon2::default()
+            .hash_password_into(self.passphrase.as_bytes(), &salt, &mut key)
+            .map_err(|e| anyhow::anyhow!("Failed to derive input key: {e}"))?;
+        keys.insert(salt, key);
+        Ok(key)
+    }
+}
+
+static CIPHER: OnceLock<Cipher> = OnceLock::new();
+
+/// The cipher for the passphrase configured in the workspace, if there is one.
+pub fn configured_cipher() -> anyhow::Result<Option<&'static Cipher>> {
+    if let Some(cipher) = CIPHER.get() {
+        return Ok(Some(cipher));
+    }
+    let passphrase = config::workspace().input_passphrase()?;
+    Ok(passphrase.map(|passphrase| CIPHER.get_or_init(|| Cipher::new(passphrase))))
+}
+
+/// Like [`configured_cipher`], failing when no passphrase is configured.
+pub fn cipher() -> anyhow::Result<&'static Cipher> {
+    configured_cipher()?.with_context(|| {
+        format!(
+            "No input passphrase found. Set {} or `input_key_file` in aoc.toml.",
+            config::workspace().config.input_key_env
+        )
+    })
+}
+
+pub fn is_encrypted(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == EXTENSION)
+}
+
+/// Reads and decrypts the encrypted input at `path`.
+pub fn read(path: &Path) -> anyhow::Result<String> {
+    let blob = std::fs::read(path)?;
+    cipher()?
+        .decrypt(&blob)
+        .with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// Encrypts `content` and writes it to `path`, creating parent directories.
+pub fn write(path: &Path, content: &str) -> anyhow::Result<()> {
+    let blob = cipher()?.encrypt(content)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, blob)?;
+    Ok(())
+}
+
+/// Where the input of a day is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Plaintext,
+    Encrypted,
+    /// Stored both ways with the same content.
+    InSync,
+    /// Stored both ways with different content.
+    Differs,
+    /// Stored both ways, but there is no passphrase to compare them.
+    Both,
+}
+
+impl State {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            State::Plaintext => "plaintext only",
+            State::Encrypted => "encrypted only",
+            State::InSync => "in sync",
+            State::Differs => "differs",
+            State::Both => "both (no passphrase to compare)",
+        }
+    }
+}
+
+/// State of the input for a day, or `None` if it is not stored at all.
+pub fn state(year: u16, day: u8) -> anyhow::Result<Option<State>> {
+    let workspace = config::workspace();
+    let plaintext = workspace.input_path(year, day);
+    let encrypted = workspace.encrypted_input_path(year, day);
+    let state = match (plaintext.exists(), encrypted.exists()) {
+        (false, false) => return Ok(None),
+        (true, false) => State::Plaintext,
+        (false, true) => State::Encrypted,
+        (true, true) => match configured_cipher()? {
+            Some(_) if std::fs::read_to_string(&plaintext)? == read(&encrypted)? => State::InSync,
+            Some(_) => State::Differs,
+            None => State::Both,
+        },
+    };
+    Ok(Some(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cipher;
+
+    #[test]
+    fn round_trip() {
+        let cipher = Cipher::new("correct horse".to_string());
+        let blob = cipher.encrypt("1   2\n3   4\n").unwrap();
+        assert!(!blob.windows(5).any(|w| w == b"1   2"));
+        assert_eq!(cipher.decrypt(&blob).unwrap(), "1   2\n3   4\n");
+
+        // A fresh cipher with the same passphrase derives the key from the stored salt.
+        let other = Cipher::new("correct horse".to_string());
+        assert_eq!(other.decrypt(&blob).unwrap(), "1   2\n3   4\n");
+    }
+
+    #[test]
+    fn reject_wrong_passphrase_and_tampering() {
+        let blob = Cipher::new("correct horse".to_string())
+            .encrypt("input")
+            .unwrap();
+
+        let wrong = Cipher::new("battery staple".to_string());
+        assert!(wrong.decrypt(&blob).is_err());
+
+        let cipher = Cipher::new("correct horse".to_string());
+        let mut tampered = blob.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(cipher.decrypt(&tampered).is_err());
+        assert!(cipher.decrypt(b"input").is_err());
+        assert!(cipher.decrypt(&blob[..20]).is_err());
+    }
+}
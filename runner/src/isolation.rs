@@ -0,0 +1,234 @@
+use crate::STDIN_INPUT;
+use crate::report::RunReport;
+use anyhow::Context;
+use std::any::Any;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::panic::AssertUnwindSafe;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+use utils::memory;
+use utils::solution::Solver;
+
+/// Marks the line of a child's stdout that carries its [`RunReport`], so that
+/// whatever the solver prints itself can be told apart from it.
+pub const REPORT_PREFIX: &str = "@@aoc-report ";
+
+static CHILD_COMMAND: OnceLock<ChildCommand> = OnceLock::new();
+
+/// How to start a process that runs a single solver for [`run_in_child`]:
+/// `program` with `args`, followed by `--year Y --day D --input PATH` and
+/// `--memory` when allocation tracking is enabled. The process must call
+/// [`run_one`] with those values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChildCommand {
+    pub program: PathBuf,
+    pub args: Vec<String>,
+}
+
+/// Sets the command used to run solvers in child processes. Fails if it has
+/// already been set.
+pub fn set_child_command(command: ChildCommand) -> anyhow::Result<()> {
+    CHILD_COMMAND
+        .set(command)
+        .map_err(|_| anyhow::anyhow!("The child command has already been set"))
+}
+
+/// The child side of [`run_in_child`]: runs the solver for `year` and `day`
+/// on the input at `input_path` and writes its report with [`print_report`].
+pub fn run_one(year: u16, day: u8, input_path: &Path, memory: bool) -> anyhow::Result<()> {
+    if memory {
+        memory::enable();
+    }
+    let Some(solver) = crate::find_solver(year, day) else {
+        anyhow::bail!("No solver found for year {year} day {day}.");
+    };
+    print_report(&crate::run(solver, input_path))
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Keeps panics caught by [`catch_panic`] off stderr, since they end up in the
+/// solver report instead. Any other panic is reported as usual.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if CATCHING.get() {
+            LOCATION.set(info.location().map(|location| location.to_string()));
+        } else {
+            default_hook(info);
+        }
+    }));
+}
+
+/// Runs `f`, turning a panic into an error carrying the panic message.
+pub fn catch_panic<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    let was_catching = CATCHING.replace(true);
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+
+    result.unwrap_or_else(|payload| {
+        let message = panic_message(payload.as_ref());
+        match LOCATION.take() {
+            Some(location) => anyhow::bail!("Solver panicked at {location}: {message}"),
+            None => anyhow::bail!("Solver panicked: {message}"),
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Runs `solver` in a child process and kills it once `timeout` has passed.
+/// The child is started with the command given to [`set_child_command`]. A
+/// crash of the child (e.g. a stack overflow) is reported as a failure rather
+/// than taking the caller down.
+pub fn run_in_child(solver: &Solver, input_path: &Path, timeout: Duration) -> RunReport {
+    spawn_and_wait(solver, input_path, None, timeout)
+        .unwrap_or_else(|err| RunReport::failed(solver, input_path, &err))
+}
+
+/// Like [`run_in_child`], handing `input` to the child through its stdin.
+pub fn solve_in_child(solver: &Solver, input: &str, timeout: Duration) -> RunReport {
+    let input_path = Path::new(STDIN_INPUT);
+    spawn_and_wait(solver, input_path, Some(input), timeout)
+        .unwrap_or_else(|err| RunReport::failed(solver, input_path, &err))
+}
+
+fn spawn_and_wait(
+    solver: &Solver,
+    input_path: &Path,
+    input: Option<&str>,
+    timeout: Duration,
+) -> anyhow::Result<RunReport> {
+    let Some(child) = CHILD_COMMAND.get() else {
+        anyhow::bail!("Cannot run solvers in a child process before `set_child_command`");
+    };
+    let mut command = Command::new(&child.program);
+    command
+        .args(&child.args)
+        .args(["--year", &solver.year.to_string()])
+        .args(["--day", &solver.day.to_string()])
+        .arg("--input")
+        .arg(input_path);
+    if memory::is_enabled() {
+        command.arg("--memory");
+    }
+    // The child reads stdin itself when the input comes from there.
+    let stdin = if input.is_some() {
+        Stdio::piped()
+    } else if input_path == Path::new(STDIN_INPUT) {
+        Stdio::inherit()
+    } else {
+        Stdio::null()
+    };
+    let mut child = command.stdin(stdin).stdout(Stdio::piped()).spawn()?;
+    if let (Some(input), Some(mut pipe)) = (input, child.stdin.take()) {
+        // Written from a separate thread, since a large input could fill the pipe
+        // before the child gets to read it.
+        let input = input.to_string();
+        std::thread::spawn(move || pipe.write_all(input.as_bytes()));
+    }
+    // Read from a separate thread as well, so that a solver printing more than
+    // the pipe holds is not blocked until the timeout.
+    let stdout = child
+        .stdout
+        .take()
+        .context("Solver process has no stdout")?;
+    let reader = std::thread::spawn(move || read_report(BufReader::new(stdout)));
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            let report = reader
+                .join()
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("Failed to read solver output")));
+            if !status.success() {
+                anyhow::bail!("Solver process exited with {status}");
+            }
+            return report;
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(RunReport::timed_out(solver, input_path, timeout));
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+/// Writes `report` to stdout for the parent process to read with
+/// [`read_report`]. Meant to be the last output of a child process.
+pub fn print_report(report: &RunReport) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{REPORT_PREFIX}{}", serde_json::to_string(report)?)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Reads the stdout of a child process up to its end, returning the last
+/// report written by [`print_report`]. Anything else is what the solver
+/// printed itself and is passed on to stderr.
+pub fn read_report(output: impl BufRead) -> anyhow::Result<RunReport> {
+    let mut report = None;
+    for line in output.split(b'\n') {
+        let line = String::from_utf8_lossy(&line?).into_owned();
+        // The solver may have left its last line unterminated.
+        match line.split_once(REPORT_PREFIX) {
+            Some((printed, json)) => {
+                if !printed.is_empty() {
+                    eprintln!("{printed}");
+                }
+                report = Some(json.to_string());
+            }
+            None => eprintln!("{line}"),
+        }
+    }
+    let json = report.context("Solver process did not write a report")?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catch_panic;
+
+    #[test]
+    fn catch_panics() {
+        let ok = catch_panic(|| Ok(1));
+        assert_eq!(ok.unwrap(), 1);
+
+        let err = catch_panic::<()>(|| Err(anyhow::anyhow!("plain error")));
+        assert_eq!(err.unwrap_err().to_string(), "plain error");
+
+        let panicked = catch_panic::<()>(|| panic!("boom {}", 42));
+        let message = panicked.unwrap_err().to_string();
+        assert!(message.starts_with("Solver panicked"), "{message}");
+        assert!(message.ends_with("boom 42"), "{message}");
+
+        let unimplemented = catch_panic::<()>(|| unimplemented!("Invalid direction"));
+        assert!(
+            unimplemented
+                .unwrap_err()
+                .to_string()
+                .contains("not implemented: Invalid direction")
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::config;
+use crate::report::RunReport;
+use crate::report::Status;
+use anyhow::Context;
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::Path;
+
+/// The last run of each day on its default input, kept in `last_runs_file` so
+/// that answers and timings can be shown without running anything. Stored as a
+/// JSON array of [`RunReport`]s, sorted by year and day.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(transparent)]
+pub struct LastRuns {
+    runs: Vec<RunReport>,
+}
+
+impl LastRuns {
+    /// Loads the file at `path`, returning no runs if it does not exist.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn load_for_workspace() -> anyhow::Result<Self> {
+        Self::load(&config::workspace().last_runs_path())
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&RunReport> {
+        self.runs.iter().find(|r| r.year == year && r.day == day)
+    }
+
+    /// Replaces the runs of the days in `reports`. Skipped runs say nothing
+    /// about a solver, so they are left out.
+    pub fn update(&mut self, reports: &[RunReport]) {
+        for report in reports.iter().filter(|r| r.status != Status::Skipped) {
+            self.runs
+                .retain(|r| (r.year, r.day) != (report.year, report.day));
+            self.runs.push(report.clone());
+        }
+        self.runs.sort_by_key(|r| (r.year, r.day));
+    }
+}
+
+/// Adds `reports`, which must come from the default inputs, to the workspace's
+/// last runs.
+pub fn record(reports: &[RunReport]) -> anyhow::Result<()> {
+    if reports.iter().all(|r| r.status == Status::Skipped) {
+        return Ok(());
+    }
+    let path = config::workspace().last_runs_path();
+    let mut last_runs = LastRuns::load(&path)?;
+    last_runs.update(reports);
+    last_runs.save(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LastRuns;
+    use crate::report::Durations;
+    use crate::report::RunReport;
+    use crate::report::Status;
+
+    fn report(day: u8, status: Status, part_one: &str) -> RunReport {
+        RunReport {
+            year: 2024,
+            day,
+            status,
+            part_one: Some(part_one.to_string()),
+            part_two: None,
+            durations: Durations::default(),
+            memory: None,
+            error: None,
+            input: format!("{day:02}.txt"),
+        }
+    }
+
+    #[test]
+    fn keep_latest_run_per_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".aoc/last-runs.json");
+        let mut last_runs = LastRuns::load(&path).unwrap();
+        assert!(last_runs.get(2024, 1).is_none());
+
+        last_runs.update(&[
+            report(2, Status::Succeeded, "2"),
+            report(1, Status::Succeeded, "1"),
+        ]);
+        last_runs.update(&[
+            report(1, Status::Failed, ""),
+            report(2, Status::Skipped, ""),
+        ]);
+        last_runs.save(&path).unwrap();
+
+        let loaded = LastRuns::load(&path).unwrap();
+        assert_eq!(loaded.get(2024, 1).unwrap().status, Status::Failed);
+        assert_eq!(loaded.get(2024, 2).unwrap().part_one.as_deref(), Some("2"));
+        assert_eq!(
+            loaded.runs.iter().map(|r| r.day).collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+}
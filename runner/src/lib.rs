@@ -0,0 +1,218 @@
+//! Finds solvers, resolves their inputs and runs them, with structured results.
+//! The `cli` binary is a thin layer over this crate, and other tools can drive
+//! solvers through it without going through the binary:
+//!
+//! ```ignore
+//! let solver = runner::find_solver(2024, 1).unwrap();
+//! let report = runner::run(solver, &runner::default_input_path(solver));
+//! println!("{:?}", report.part_one);
+//! ```
+pub mod answers;
+pub mod bench;
+pub mod clock;
+pub mod config;
+pub mod inputs;
+pub mod isolation;
+pub mod last_runs;
+pub mod report;
+pub mod stats;
+
+use crate::clock::Stopwatch;
+use crate::report::BatchReport;
+use crate::report::RunReport;
+use anyhow::Context;
+use aoc24 as _;
+use aoc25 as _;
+use rayon::iter::IntoParallelRefIterator;
+use rayon::iter::ParallelIterator;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use utils::solution::Solution;
+use utils::solution::Solver;
+
+/// Input path that makes a solver read its input from stdin.
+pub const STDIN_INPUT: &str = "-";
+
+/// Suffix of the expected answers saved next to example inputs, e.g. `01-1.expected.txt`.
+pub const ANSWER_SUFFIX: &str = ".expected.txt";
+
+/// Selects solvers by year and day. `None` matches everything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Filter {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+}
+
+impl Filter {
+    pub fn matches(&self, solver: &Solver) -> bool {
+        self.year.is_none_or(|year| year == solver.year)
+            && self.day.is_none_or(|day| day == solver.day)
+    }
+}
+
+/// How [`run_all`] and [`run_many`] run solvers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Number of solvers to run concurrently. `0` uses one per available core.
+    pub jobs: usize,
+    /// Kill solvers still running after this long. Each solver then runs in a
+    /// child process, started as set with [`isolation::set_child_command`].
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            jobs: 1,
+            timeout: None,
+        }
+    }
+}
+
+impl RunOptions {
+    /// Number of threads to run solvers on.
+    pub fn threads(&self) -> usize {
+        match self.jobs {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            jobs => jobs,
+        }
+    }
+}
+
+/// Registered solvers matching `filter`, sorted by year and day.
+pub fn solvers(filter: &Filter) -> Vec<&'static Solver> {
+    let mut solvers = inventory::iter::<Solver>()
+        .filter(|solver| filter.matches(solver))
+        .collect::<Vec<_>>();
+    solvers.sort_by_key(|s| (s.year, s.day));
+    solvers
+}
+
+pub fn find_solver(year: u16, day: u8) -> Option<&'static Solver> {
+    inventory::iter::<Solver>().find(|s| s.year == year && s.day == day)
+}
+
+/// The plaintext input of a solver, or its encrypted copy if there is no plaintext one.
+pub fn default_input_path(solver: &Solver) -> PathBuf {
+    day_input_path(solver.year, solver.day)
+}
+
+/// Like [`default_input_path`], for a day that may not have a solver yet.
+pub fn day_input_path(year: u16, day: u8) -> PathBuf {
+    let workspace = config::workspace();
+    let plaintext = workspace.input_path(year, day);
+    let encrypted = workspace.encrypted_input_path(year, day);
+    if !plaintext.exists() && encrypted.exists() {
+        encrypted
+    } else {
+        plaintext
+    }
+}
+
+/// Pairs every solver with its input in the year crate.
+pub fn default_inputs(solvers: &[&'static Solver]) -> Vec<(&'static Solver, PathBuf)> {
+    solvers
+        .iter()
+        .map(|solver| (*solver, default_input_path(solver)))
+        .collect()
+}
+
+/// Lists the input files in `dir`, sorted by name. Hidden files and the expected
+/// answers saved next to example inputs are left out.
+pub fn inputs_in_dir(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read input directory {}", dir.display()))?;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_file() && !name.starts_with('.') && !name.ends_with(ANSWER_SUFFIX) {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+pub fn input_exists(input_path: &Path) -> bool {
+    input_path == Path::new(STDIN_INPUT) || input_path.exists()
+}
+
+/// Reads an input file, decrypting it if it comes from the encrypted store, or
+/// stdin for [`STDIN_INPUT`].
+pub fn read_input(input_path: &Path) -> anyhow::Result<String> {
+    if input_path == Path::new(STDIN_INPUT) {
+        return std::io::read_to_string(std::io::stdin())
+            .map_err(|e| anyhow::anyhow!("Failed to read input from stdin: {e}"));
+    }
+    if inputs::is_encrypted(input_path) {
+        return inputs::read(input_path);
+    }
+    std::fs::read_to_string(input_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read input file: {e}"))
+}
+
+/// Solves `input`, turning a panic into an error.
+pub fn solve(solver: &Solver, input: &str) -> anyhow::Result<Solution> {
+    isolation::catch_panic(|| solver.solve(input))
+}
+
+/// Runs `solver` on the input at `input_path`, timing each phase. A missing
+/// input is skipped and a panic is reported as a failure.
+pub fn run(solver: &Solver, input_path: &Path) -> RunReport {
+    run_with(solver, input_path, &RunOptions::default())
+}
+
+/// Like [`run`], in a child process killed after `options.timeout` if one is set.
+pub fn run_with(solver: &Solver, input_path: &Path, options: &RunOptions) -> RunReport {
+    if !input_exists(input_path) {
+        return RunReport::skipped(solver, input_path);
+    }
+    if let Some(timeout) = options.timeout {
+        return isolation::run_in_child(solver, input_path, timeout);
+    }
+    let result = read_input(input_path)
+        .and_then(|input| isolation::catch_panic(|| solver.solve_timed(&input)));
+    match result {
+        Ok((solution, timings)) => RunReport::succeeded(solver, input_path, solution, timings),
+        Err(err) => RunReport::failed(solver, input_path, &err),
+    }
+}
+
+/// Runs `solver` on `input` held in memory, timing each phase. With
+/// `options.timeout`, the input is handed to a child process through its stdin.
+/// The report's input is [`STDIN_INPUT`].
+pub fn run_input(solver: &Solver, input: &str, options: &RunOptions) -> RunReport {
+    if let Some(timeout) = options.timeout {
+        return isolation::solve_in_child(solver, input, timeout);
+    }
+    let input_path = Path::new(STDIN_INPUT);
+    match isolation::catch_panic(|| solver.solve_timed(input)) {
+        Ok((solution, timings)) => RunReport::succeeded(solver, input_path, solution, timings),
+        Err(err) => RunReport::failed(solver, input_path, &err),
+    }
+}
+
+/// Runs each solver on its input, on a pool of `options.jobs` threads.
+/// Reports keep the order of `runs`.
+pub fn run_all(
+    runs: &[(&'static Solver, PathBuf)],
+    options: &RunOptions,
+) -> anyhow::Result<BatchReport> {
+    let stopwatch = Stopwatch::start();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.threads())
+        .build()?;
+    let reports = pool.install(|| {
+        runs.par_iter()
+            .map(|(solver, input_path)| run_with(solver, input_path, options))
+            .collect()
+    });
+    Ok(BatchReport::new(reports, stopwatch.elapsed()))
+}
+
+/// Runs every solver matching `filter` on its default input.
+pub fn run_many(filter: &Filter, options: &RunOptions) -> anyhow::Result<BatchReport> {
+    run_all(&default_inputs(&solvers(filter)), options)
+}
@@ -0,0 +1,326 @@
+use crate::clock::Elapsed;
+use human_repr::HumanDuration;
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use utils::memory::AllocStats;
+use utils::solution::PhaseMemory;
+use utils::solution::PhaseTimings;
+use utils::solution::Solution;
+use utils::solution::Solver;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Succeeded,
+    Failed,
+    TimedOut,
+    Skipped,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Succeeded => "succeeded",
+            Status::Failed => "failed",
+            Status::TimedOut => "timed_out",
+            Status::Skipped => "skipped",
+        }
+    }
+}
+
+/// Durations in milliseconds. Phases are only known for phased solvers.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Durations {
+    pub total_ms: Option<f64>,
+    pub parse_ms: Option<f64>,
+    pub part_one_ms: Option<f64>,
+    pub part_two_ms: Option<f64>,
+}
+
+impl From<&PhaseTimings> for Durations {
+    fn from(timings: &PhaseTimings) -> Self {
+        Self {
+            total_ms: Some(millis(timings.total)),
+            parse_ms: timings.parse.map(millis),
+            part_one_ms: timings.part_one.map(millis),
+            part_two_ms: timings.part_two.map(millis),
+        }
+    }
+}
+
+/// Heap usage of a run or phase, see [`AllocStats`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocUsage {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    pub peak_bytes: u64,
+}
+
+impl From<AllocStats> for AllocUsage {
+    fn from(stats: AllocStats) -> Self {
+        Self {
+            allocations: stats.allocations,
+            allocated_bytes: stats.allocated_bytes,
+            peak_bytes: stats.peak_bytes,
+        }
+    }
+}
+
+impl From<AllocUsage> for AllocStats {
+    fn from(usage: AllocUsage) -> Self {
+        Self {
+            allocations: usage.allocations,
+            allocated_bytes: usage.allocated_bytes,
+            peak_bytes: usage.peak_bytes,
+        }
+    }
+}
+
+/// Heap usage per phase, only present when allocation tracking is enabled.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Memory {
+    pub total: AllocUsage,
+    pub parse: Option<AllocUsage>,
+    pub part_one: Option<AllocUsage>,
+    pub part_two: Option<AllocUsage>,
+}
+
+impl From<&PhaseMemory> for Memory {
+    fn from(memory: &PhaseMemory) -> Self {
+        Self {
+            total: memory.total.into(),
+            parse: memory.parse.map(Into::into),
+            part_one: memory.part_one.map(Into::into),
+            part_two: memory.part_two.map(Into::into),
+        }
+    }
+}
+
+impl From<&Memory> for PhaseMemory {
+    fn from(memory: &Memory) -> Self {
+        Self {
+            total: memory.total.into(),
+            parse: memory.parse.map(Into::into),
+            part_one: memory.part_one.map(Into::into),
+            part_two: memory.part_two.map(Into::into),
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn from_millis(ms: f64) -> Duration {
+    Duration::from_secs_f64(ms / 1000.0)
+}
+
+/// Outcome of running a single solver. Reports are also how a solver run in a
+/// child process (see [`crate::isolation::run_in_child`]) hands its result back
+/// to the parent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunReport {
+    pub year: u16,
+    pub day: u8,
+    pub status: Status,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+    pub durations: Durations,
+    pub memory: Option<Memory>,
+    pub error: Option<String>,
+    pub input: String,
+}
+
+impl RunReport {
+    pub fn skipped(solver: &Solver, input_path: &Path) -> Self {
+        Self::new(solver, input_path, Status::Skipped)
+    }
+
+    pub fn failed(solver: &Solver, input_path: &Path, error: &anyhow::Error) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(solver, input_path, Status::Failed)
+        }
+    }
+
+    pub fn succeeded(
+        solver: &Solver,
+        input_path: &Path,
+        solution: Solution,
+        timings: PhaseTimings,
+    ) -> Self {
+        Self {
+            part_one: Some(solution.part_one),
+            part_two: Some(solution.part_two),
+            durations: Durations::from(&timings),
+            memory: timings.memory.as_ref().map(Memory::from),
+            ..Self::new(solver, input_path, Status::Succeeded)
+        }
+    }
+
+    fn new(solver: &Solver, input_path: &Path, status: Status) -> Self {
+        Self {
+            year: solver.year,
+            day: solver.day,
+            status,
+            part_one: None,
+            part_two: None,
+            durations: Durations::default(),
+            memory: None,
+            error: None,
+            input: input_path.display().to_string(),
+        }
+    }
+
+    pub fn timed_out(solver: &Solver, input_path: &Path, timeout: Duration) -> Self {
+        Self {
+            error: Some(format!("Timed out after {}", timeout.human_duration())),
+            ..Self::new(solver, input_path, Status::TimedOut)
+        }
+    }
+
+    /// Answers of a successful run.
+    pub fn solution(&self) -> Option<Solution> {
+        if self.status != Status::Succeeded {
+            return None;
+        }
+        Some(Solution {
+            part_one: self.part_one.clone()?,
+            part_two: self.part_two.clone()?,
+        })
+    }
+
+    /// Phase timings of a successful run.
+    pub fn timings(&self) -> Option<PhaseTimings> {
+        if self.status != Status::Succeeded {
+            return None;
+        }
+        Some(PhaseTimings {
+            parse: self.durations.parse_ms.map(from_millis),
+            part_one: self.durations.part_one_ms.map(from_millis),
+            part_two: self.durations.part_two_ms.map(from_millis),
+            total: from_millis(self.durations.total_ms?),
+            memory: self.memory.as_ref().map(PhaseMemory::from),
+        })
+    }
+}
+
+/// Totals matching the "===== Summary =====" block of the text output.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub total_matched: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub timed_out: usize,
+    pub skipped: usize,
+    /// Sum of the wall-clock times of the individual solvers. This is not CPU
+    /// time: a solver that runs on several threads counts once.
+    pub summed_solver_time_ms: f64,
+    /// Elapsed time for the whole run, lower than the sum when solvers run in parallel.
+    pub wall_clock_ms: f64,
+    /// CPU time of the whole run, summed over every thread and child process.
+    /// `None` where the platform does not report it.
+    pub cpu_time_ms: Option<f64>,
+    pub missing_inputs: Vec<String>,
+}
+
+impl Summary {
+    pub fn from_reports(reports: &[RunReport], elapsed: Elapsed) -> Self {
+        let count = |status| reports.iter().filter(|r| r.status == status).count();
+        Self {
+            total_matched: reports.len(),
+            succeeded: count(Status::Succeeded),
+            failed: count(Status::Failed),
+            timed_out: count(Status::TimedOut),
+            skipped: count(Status::Skipped),
+            summed_solver_time_ms: reports.iter().filter_map(|r| r.durations.total_ms).sum(),
+            wall_clock_ms: millis(elapsed.wall),
+            cpu_time_ms: elapsed.cpu.map(millis),
+            missing_inputs: reports
+                .iter()
+                .filter(|r| r.status == Status::Skipped)
+                .map(|r| r.input.clone())
+                .collect(),
+        }
+    }
+
+    pub fn summed_solver_time(&self) -> Duration {
+        from_millis(self.summed_solver_time_ms)
+    }
+
+    pub fn wall_clock(&self) -> Duration {
+        from_millis(self.wall_clock_ms)
+    }
+
+    pub fn cpu_time(&self) -> Option<Duration> {
+        self.cpu_time_ms.map(from_millis)
+    }
+}
+
+/// Reports of several solvers, in the order they were requested, and their totals.
+#[derive(Serialize, Debug, Clone)]
+pub struct BatchReport {
+    pub solvers: Vec<RunReport>,
+    pub summary: Summary,
+}
+
+impl BatchReport {
+    pub fn new(solvers: Vec<RunReport>, elapsed: Elapsed) -> Self {
+        let summary = Summary::from_reports(&solvers, elapsed);
+        Self { solvers, summary }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RunReport;
+    use super::Summary;
+    use crate::clock::Elapsed;
+    use std::path::Path;
+    use std::time::Duration;
+    use utils::solution::PhaseTimings;
+    use utils::solution::Solution;
+    use utils::solution::Solver;
+
+    fn dummy(_: &str) -> anyhow::Result<Solution> {
+        unreachable!()
+    }
+
+    #[test]
+    fn summarize() {
+        let solver = Solver::new(2024, 1, dummy);
+        let timings = PhaseTimings {
+            total: Duration::from_millis(6),
+            ..PhaseTimings::default()
+        };
+        let solution = Solution {
+            part_one: "11".into(),
+            part_two: "31".into(),
+        };
+        let reports = vec![
+            RunReport::succeeded(&solver, Path::new("in/01.txt"), solution, timings),
+            RunReport::failed(&solver, Path::new("in/01.txt"), &anyhow::anyhow!("boom")),
+            RunReport::skipped(&solver, Path::new("in/02.txt")),
+        ];
+
+        let elapsed = Elapsed {
+            wall: Duration::from_millis(4),
+            cpu: Some(Duration::from_millis(9)),
+        };
+        let summary = Summary::from_reports(&reports, elapsed);
+        assert_eq!(summary.total_matched, 3);
+        assert_eq!(summary.succeeded, 1);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.summed_solver_time_ms, 6.0);
+        assert_eq!(summary.wall_clock_ms, 4.0);
+        assert_eq!(summary.cpu_time_ms, Some(9.0));
+        assert_eq!(summary.missing_inputs, vec!["in/02.txt".to_string()]);
+
+        let timings = reports[0].timings().unwrap();
+        assert_eq!(timings.total, Duration::from_millis(6));
+        assert!(reports[1].timings().is_none());
+    }
+}
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+/// Summary statistics over benchmark samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    /// Samples outside the inner Tukey fences (1.5 IQR from the quartiles).
+    pub mild_outliers: usize,
+    /// Samples outside the outer Tukey fences (3 IQR from the quartiles).
+    pub severe_outliers: usize,
+}
+
+impl Stats {
+    /// Computes statistics for `samples`, returning `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut seconds: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        seconds.sort_by(f64::total_cmp);
+
+        let n = seconds.len() as f64;
+        let mean = seconds.iter().sum::<f64>() / n;
+        let variance = if seconds.len() > 1 {
+            seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&seconds, 0.25);
+        let q3 = percentile(&seconds, 0.75);
+        let iqr = q3 - q1;
+        let outside = |k: f64| {
+            seconds
+                .iter()
+                .filter(|s| **s < q1 - k * iqr || **s > q3 + k * iqr)
+                .count()
+        };
+        let severe_outliers = outside(3.0);
+        let mild_outliers = outside(1.5) - severe_outliers;
+
+        Some(Self {
+            samples: seconds.len(),
+            min: Duration::from_secs_f64(seconds[0]),
+            max: Duration::from_secs_f64(seconds[seconds.len() - 1]),
+            median: Duration::from_secs_f64(percentile(&seconds, 0.5)),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            mild_outliers,
+            severe_outliers,
+        })
+    }
+
+    pub fn outliers(&self) -> usize {
+        self.mild_outliers + self.severe_outliers
+    }
+}
+
+/// Linearly interpolated percentile of sorted values, with `p` in `[0, 1]`.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn compute_stats() {
+        let stats = Stats::from_samples(&millis(&[4, 2, 3, 5, 1])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.stddev.as_micros(), 1581);
+        assert_eq!(stats.outliers(), 0);
+
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn count_outliers() {
+        let stats = Stats::from_samples(&millis(&[10, 10, 11, 11, 12, 12, 18, 100])).unwrap();
+        assert_eq!(stats.mild_outliers, 1);
+        assert_eq!(stats.severe_outliers, 1);
+    }
+}
@@ -0,0 +1,105 @@
+//! Runs solvers in child processes. This test has no libtest harness so that
+//! the test binary can be its own child: started as `child`, it runs the
+//! requested solver and writes its report, like `cli run-one` does.
+
+use runner::isolation;
+use runner::isolation::ChildCommand;
+use runner::report::Status;
+use std::path::Path;
+use std::time::Duration;
+use std::time::Instant;
+use utils::solution::Solution;
+use utils::solution::Solver;
+
+inventory::submit! {
+    Solver::new(2015, 1, sleep_forever)
+}
+
+inventory::submit! {
+    Solver::new(2015, 2, print_a_lot)
+}
+
+fn sleep_forever(_: &str) -> anyhow::Result<Solution> {
+    loop {
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
+
+/// Prints more than a pipe holds (64 KiB on Linux), ending with an
+/// unterminated line.
+fn print_a_lot(input: &str) -> anyhow::Result<Solution> {
+    for _ in 0..100 {
+        println!("{}", ".".repeat(1024));
+    }
+    print!("unterminated");
+    Ok(Solution {
+        part_one: input.trim().to_string(),
+        part_two: "2".to_string(),
+    })
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) == Some("child") {
+        run_child(&args);
+        return;
+    }
+    isolation::set_child_command(ChildCommand {
+        program: std::env::current_exe().unwrap(),
+        args: vec!["child".to_string()],
+    })
+    .unwrap();
+
+    let tests: [(&str, fn()); 2] = [
+        (
+            "kills_solvers_that_never_finish",
+            kills_solvers_that_never_finish,
+        ),
+        (
+            "reads_reports_after_lots_of_output",
+            reads_reports_after_lots_of_output,
+        ),
+    ];
+    for (name, test) in tests {
+        test();
+        println!("test {name} ... ok");
+    }
+}
+
+fn run_child(args: &[String]) {
+    let value = |flag: &str| {
+        let index = args.iter().position(|arg| arg == flag).unwrap();
+        args[index + 1].clone()
+    };
+    isolation::run_one(
+        value("--year").parse().unwrap(),
+        value("--day").parse().unwrap(),
+        Path::new(&value("--input")),
+        args.iter().any(|arg| arg == "--memory"),
+    )
+    .unwrap();
+}
+
+fn input_file() -> tempfile::NamedTempFile {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(file.path(), "42\n").unwrap();
+    file
+}
+
+fn kills_solvers_that_never_finish() {
+    let input = input_file();
+    let solver = runner::find_solver(2015, 1).unwrap();
+    let started = Instant::now();
+    let report = isolation::run_in_child(solver, input.path(), Duration::from_millis(300));
+    assert_eq!(report.status, Status::TimedOut);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+fn reads_reports_after_lots_of_output() {
+    let input = input_file();
+    let solver = runner::find_solver(2015, 2).unwrap();
+    let report = isolation::run_in_child(solver, input.path(), Duration::from_secs(30));
+    assert_eq!(report.status, Status::Succeeded, "{:?}", report.error);
+    assert_eq!(report.part_one.as_deref(), Some("42"));
+    assert_eq!(report.part_two.as_deref(), Some("2"));
+}
@@ -0,0 +1,89 @@
+use runner::Filter;
+use runner::RunOptions;
+use runner::report::Status;
+use std::path::PathBuf;
+
+const DAY_ONE_INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+#[test]
+fn finds_solvers() {
+    let solver = runner::find_solver(2024, 1).unwrap();
+    assert_eq!((solver.year, solver.day), (2024, 1));
+    assert!(runner::find_solver(2024, 26).is_none());
+
+    let solvers = runner::solvers(&Filter {
+        year: Some(2024),
+        day: None,
+    });
+    assert!(solvers.len() > 1);
+    assert!(solvers.iter().all(|s| s.year == 2024));
+    assert!(solvers.is_sorted_by_key(|s| s.day));
+}
+
+#[test]
+fn runs_solver_on_input_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("01.txt");
+    std::fs::write(&input, DAY_ONE_INPUT).unwrap();
+    let solver = runner::find_solver(2024, 1).unwrap();
+
+    let report = runner::run(solver, &input);
+    assert_eq!(report.status, Status::Succeeded);
+    assert_eq!(report.part_one.as_deref(), Some("11"));
+    assert_eq!(report.part_two.as_deref(), Some("31"));
+    assert!(report.timings().is_some());
+
+    let missing = runner::run(solver, &dir.path().join("missing.txt"));
+    assert_eq!(missing.status, Status::Skipped);
+
+    let solution = runner::solve(solver, DAY_ONE_INPUT).unwrap();
+    assert_eq!(solution.part_one, "11");
+
+    let report = runner::run_input(solver, DAY_ONE_INPUT, &RunOptions::default());
+    assert_eq!(report.status, Status::Succeeded);
+    assert_eq!(report.part_two.as_deref(), Some("31"));
+    assert_eq!(report.input, runner::STDIN_INPUT);
+}
+
+#[test]
+fn runs_batches_in_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let solver = runner::find_solver(2024, 1).unwrap();
+    let runs = (0..4)
+        .map(|i| {
+            let path = dir.path().join(format!("{i}.txt"));
+            std::fs::write(&path, format!("{i}   {i}\n")).unwrap();
+            (solver, path)
+        })
+        .chain([(solver, PathBuf::from("missing.txt"))])
+        .collect::<Vec<_>>();
+
+    let batch = runner::run_all(
+        &runs,
+        &RunOptions {
+            jobs: 0,
+            timeout: None,
+        },
+    )
+    .unwrap();
+    let part_two = batch
+        .solvers
+        .iter()
+        .map(|r| r.part_two.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(part_two, [Some("0"), Some("1"), Some("2"), Some("3"), None]);
+    assert_eq!(batch.summary.succeeded, 4);
+    assert_eq!(batch.summary.skipped, 1);
+    assert_eq!(batch.summary.missing_inputs, ["missing.txt"]);
+}
+
+#[test]
+fn runs_many_from_filter() {
+    let filter = Filter {
+        year: Some(2024),
+        day: Some(1),
+    };
+    let batch = runner::run_many(&filter, &RunOptions::default()).unwrap();
+    assert_eq!(batch.solvers.len(), 1);
+    assert_eq!(batch.summary.total_matched, 1);
+}
@@ -1,3 +1,4 @@
 pub mod grid;
 pub mod input_reader;
+pub mod memory;
 pub mod solution;
@@ -0,0 +1,149 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicIsize;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+// Signed, since memory allocated before tracking was enabled may be freed afterwards.
+static CURRENT_BYTES: AtomicIsize = AtomicIsize::new(0);
+static PEAK_BYTES: AtomicIsize = AtomicIsize::new(0);
+
+/// Global allocator that counts allocations on top of the system allocator.
+/// Counting is off until [`enable`] is called, so installing it costs a single
+/// atomic load per allocation:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+///
+/// The counters are process-wide, so measurements only make sense while a
+/// single solver is running.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        if ENABLED.load(Ordering::Relaxed) {
+            CURRENT_BYTES.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Counts an allocation of `size` bytes that replaces `freed` bytes (for reallocations).
+fn record_allocation(size: usize, freed: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let delta = size as isize - freed as isize;
+    let current = CURRENT_BYTES.fetch_add(delta, Ordering::Relaxed) + delta;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+/// Starts counting allocations made through [`CountingAllocator`].
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Heap usage over a stretch of execution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, counting reallocations.
+    pub allocations: u64,
+    /// Total bytes requested, including memory freed again later.
+    pub allocated_bytes: u64,
+    /// Highest heap usage above the level at the start.
+    pub peak_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Snapshot {
+    allocations: u64,
+    allocated_bytes: u64,
+    current_bytes: isize,
+}
+
+impl Snapshot {
+    fn take() -> Self {
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            current_bytes: CURRENT_BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    fn stats_since(&self, start: &Snapshot, peak_bytes: isize) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations - start.allocations,
+            allocated_bytes: self.allocated_bytes - start.allocated_bytes,
+            peak_bytes: (peak_bytes - start.current_bytes).max(0) as u64,
+        }
+    }
+}
+
+/// Measures consecutive phases of a run. Each call to [`Recorder::phase`]
+/// ends the current phase and starts the next one; [`Recorder::total`]
+/// covers everything since [`Recorder::start`].
+pub struct Recorder {
+    start: Snapshot,
+    phase_start: Snapshot,
+    peak_bytes: isize,
+}
+
+impl Recorder {
+    pub fn start() -> Self {
+        let start = Snapshot::take();
+        PEAK_BYTES.store(start.current_bytes, Ordering::Relaxed);
+        Self {
+            start,
+            phase_start: start,
+            peak_bytes: start.current_bytes,
+        }
+    }
+
+    pub fn phase(&mut self) -> AllocStats {
+        let now = Snapshot::take();
+        let peak_bytes = PEAK_BYTES.swap(now.current_bytes, Ordering::Relaxed);
+        let stats = now.stats_since(&self.phase_start, peak_bytes);
+        self.peak_bytes = self.peak_bytes.max(peak_bytes);
+        self.phase_start = now;
+        stats
+    }
+
+    pub fn total(&self) -> AllocStats {
+        let peak_bytes = self.peak_bytes.max(PEAK_BYTES.load(Ordering::Relaxed));
+        Snapshot::take().stats_since(&self.start, peak_bytes)
+    }
+}
@@ -1,20 +1,229 @@
+use crate::memory;
+use crate::memory::AllocStats;
+use crate::memory::Recorder;
 use std::fmt::Display;
+use std::time::Duration;
+use std::time::Instant;
 
 pub struct Solution {
     pub part_one: String,
     pub part_two: String,
 }
 
+/// A solution split into separately timed phases. Whatever `parse` returns is
+/// handed to both parts in order, but either part may also run on its own
+/// (e.g. `part 2` in the REPL), so part two must not rely on part one.
+pub trait PhasedSolution {
+    type Parsed;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed>;
+    fn part_one(parsed: &mut Self::Parsed) -> anyhow::Result<String>;
+    fn part_two(parsed: &mut Self::Parsed) -> anyhow::Result<String>;
+}
+
+/// Time spent in each phase of a solver run. Solvers registered with a single
+/// `solve_all` function only report the total.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseTimings {
+    pub parse: Option<Duration>,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+    pub total: Duration,
+    /// Heap usage, only measured once [`memory::enable`] has been called.
+    pub memory: Option<PhaseMemory>,
+}
+
+/// Heap usage of each phase of a solver run, laid out like [`PhaseTimings`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseMemory {
+    pub parse: Option<AllocStats>,
+    pub part_one: Option<AllocStats>,
+    pub part_two: Option<AllocStats>,
+    pub total: AllocStats,
+}
+
+impl PhaseMemory {
+    /// Named phase allocation stats, in execution order.
+    pub fn phases(&self) -> [(&'static str, Option<AllocStats>); 3] {
+        [
+            ("Parse", self.parse),
+            ("Part one", self.part_one),
+            ("Part two", self.part_two),
+        ]
+    }
+}
+
+impl PhaseTimings {
+    /// Named phase durations, in execution order.
+    pub fn phases(&self) -> [(&'static str, Option<Duration>); 3] {
+        [
+            ("Parse", self.parse),
+            ("Part one", self.part_one),
+            ("Part two", self.part_two),
+        ]
+    }
+}
+
+pub enum SolverFn {
+    /// Parses and solves both parts in one call.
+    Combined(fn(&str) -> anyhow::Result<Solution>),
+    /// Runs a [`PhasedSolution`], timing each phase. `part` parses the input
+    /// and solves only the given part.
+    Phased {
+        solve: fn(&str) -> anyhow::Result<(Solution, PhaseTimings)>,
+        part: fn(&str, u8) -> anyhow::Result<(String, PhaseTimings)>,
+    },
+}
+
 pub struct Solver {
     pub year: u16,
     pub day: u8,
-    pub solver: fn(&str) -> anyhow::Result<Solution>,
+    pub solver: SolverFn,
 }
 
 impl Solver {
     pub const fn new(year: u16, day: u8, solver: fn(&str) -> anyhow::Result<Solution>) -> Self {
-        Solver { year, day, solver }
+        Solver {
+            year,
+            day,
+            solver: SolverFn::Combined(solver),
+        }
+    }
+
+    pub const fn phased<P: PhasedSolution>(year: u16, day: u8) -> Self {
+        Solver {
+            year,
+            day,
+            solver: SolverFn::Phased {
+                solve: run_phased::<P>,
+                part: run_phased_part::<P>,
+            },
+        }
+    }
+
+    pub fn is_phased(&self) -> bool {
+        matches!(self.solver, SolverFn::Phased { .. })
+    }
+
+    pub fn solve(&self, input: &str) -> anyhow::Result<Solution> {
+        self.solve_timed(input).map(|(solution, _)| solution)
+    }
+
+    pub fn solve_timed(&self, input: &str) -> anyhow::Result<(Solution, PhaseTimings)> {
+        match self.solver {
+            SolverFn::Combined(solve_all) => {
+                let recorder = memory::is_enabled().then(Recorder::start);
+                let start_time = Instant::now();
+                let solution = solve_all(input)?;
+                let timings = PhaseTimings {
+                    total: start_time.elapsed(),
+                    memory: recorder.map(|recorder| PhaseMemory {
+                        total: recorder.total(),
+                        ..PhaseMemory::default()
+                    }),
+                    ..PhaseTimings::default()
+                };
+                Ok((solution, timings))
+            }
+            SolverFn::Phased { solve, .. } => solve(input),
+        }
     }
+
+    /// Solves only `part` (1 or 2) of `input`. Returns `None` for solvers that
+    /// solve both parts in one call.
+    pub fn solve_part(
+        &self,
+        input: &str,
+        part: u8,
+    ) -> Option<anyhow::Result<(String, PhaseTimings)>> {
+        match self.solver {
+            SolverFn::Combined(_) => None,
+            SolverFn::Phased {
+                part: solve_part, ..
+            } => Some(solve_part(input, part)),
+        }
+    }
+}
+
+fn run_phased<P: PhasedSolution>(input: &str) -> anyhow::Result<(Solution, PhaseTimings)> {
+    let mut recorder = memory::is_enabled().then(Recorder::start);
+    let mut phase_memory = || recorder.as_mut().map(Recorder::phase);
+
+    let start_time = Instant::now();
+    let mut parsed = P::parse(input)?;
+    let parse = start_time.elapsed();
+    let parse_memory = phase_memory();
+
+    let part_one_start = Instant::now();
+    let part_one = P::part_one(&mut parsed)?;
+    let part_one_time = part_one_start.elapsed();
+    let part_one_memory = phase_memory();
+
+    let part_two_start = Instant::now();
+    let part_two = P::part_two(&mut parsed)?;
+    let part_two_time = part_two_start.elapsed();
+    let part_two_memory = phase_memory();
+
+    let timings = PhaseTimings {
+        parse: Some(parse),
+        part_one: Some(part_one_time),
+        part_two: Some(part_two_time),
+        total: start_time.elapsed(),
+        memory: recorder.map(|recorder| PhaseMemory {
+            parse: parse_memory,
+            part_one: part_one_memory,
+            part_two: part_two_memory,
+            total: recorder.total(),
+        }),
+    };
+    Ok((Solution { part_one, part_two }, timings))
+}
+
+fn run_phased_part<P: PhasedSolution>(
+    input: &str,
+    part: u8,
+) -> anyhow::Result<(String, PhaseTimings)> {
+    let solve_part: fn(&mut P::Parsed) -> anyhow::Result<String> = match part {
+        1 => P::part_one,
+        2 => P::part_two,
+        _ => anyhow::bail!("There is no part {part}"),
+    };
+    let mut recorder = memory::is_enabled().then(Recorder::start);
+    let mut phase_memory = || recorder.as_mut().map(Recorder::phase);
+
+    let start_time = Instant::now();
+    let mut parsed = P::parse(input)?;
+    let parse = start_time.elapsed();
+    let parse_memory = phase_memory();
+
+    let part_start = Instant::now();
+    let answer = solve_part(&mut parsed)?;
+    let part_time = Some(part_start.elapsed());
+    let part_memory = phase_memory();
+
+    let (part_one, part_two) = if part == 1 {
+        (part_time, None)
+    } else {
+        (None, part_time)
+    };
+    let (part_one_memory, part_two_memory) = if part == 1 {
+        (part_memory, None)
+    } else {
+        (None, part_memory)
+    };
+    let timings = PhaseTimings {
+        parse: Some(parse),
+        part_one,
+        part_two,
+        total: start_time.elapsed(),
+        memory: recorder.map(|recorder| PhaseMemory {
+            parse: parse_memory,
+            part_one: part_one_memory,
+            part_two: part_two_memory,
+            total: recorder.total(),
+        }),
+    };
+    Ok((answer, timings))
 }
 
 impl Display for Solver {